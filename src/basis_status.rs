@@ -1,5 +1,6 @@
 /// Column basis status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColBasisStatus {
     /// column is set to its upper bound
     AtUpper = 0,
@@ -17,6 +18,7 @@ pub enum ColBasisStatus {
 
 /// Row basis status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RowBasisStatus {
     /// row is set to its upper bound
     AtUpper = 0,
@@ -0,0 +1,525 @@
+use crate::elastic::{Elastic, Origin};
+use crate::{ColId, Model, ObjSense, RowId, SolvedModel, Status};
+
+/// Cuts violated by less than this (relative) value are not added.
+const CUT_TOL: f64 = 1e-9;
+
+/// A scenario subproblem of a two-stage LP, see `Benders`.
+///
+/// For a first-stage solution `x` the rows of the subproblem become
+/// `lhs - T x <= W y <= rhs - T x`, where `T` is the technology matrix.
+pub struct Scenario {
+    /// The subproblem with the second-stage columns and the rows `lhs <= W y <= rhs`.
+    pub model: Model,
+    /// The entries `(row_id, col_id, value)` of `T`, where `col_id` is a column of the master.
+    pub technology: Vec<(RowId, ColId, f64)>,
+    /// The probability of the scenario, its objective is weighted with it.
+    pub probability: f64,
+}
+
+impl Scenario {
+    /// Creates a scenario.
+    ///
+    /// # Arguments
+    /// * `model` - The subproblem.
+    /// * `technology` - The entries of the technology matrix linking it to the master.
+    /// * `probability` - The probability of the scenario.
+    pub fn new(model: Model, technology: Vec<(RowId, ColId, f64)>, probability: f64) -> Self {
+        Self {
+            model,
+            technology,
+            probability,
+        }
+    }
+}
+
+/// Why a `Benders` decomposition stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BendersStatus {
+    /// No violated cut was found or the bounds met within the gap tolerance.
+    Converged,
+    /// The iteration limit was reached.
+    IterationLimit,
+    /// The master could not be solved to optimality.
+    MasterNotOptimal(Status),
+    /// The subproblem of a scenario, given by its index, was neither optimal nor infeasible.
+    SubproblemNotOptimal(usize, Status),
+}
+
+/// Progress of one iteration of a `Benders` decomposition.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct BendersIteration {
+    /// Objective value of the master, minus infinity until every scenario has an optimality cut.
+    pub lower_bound: f64,
+    /// Objective value of the first-stage solution, infinity if a scenario is infeasible for it.
+    pub upper_bound: f64,
+    /// Number of optimality cuts added after the iteration.
+    pub num_optimality_cuts: usize,
+    /// Number of feasibility cuts added after the iteration.
+    pub num_feasibility_cuts: usize,
+}
+
+/// Result of `Benders::run`.
+#[non_exhaustive]
+pub struct BendersResult {
+    /// Why the decomposition stopped.
+    pub status: BendersStatus,
+    /// The last solved master, including the cost-to-go columns and the cuts.
+    pub master: SolvedModel,
+    /// The best lower bound.
+    pub lower_bound: f64,
+    /// The objective value of `first_stage`.
+    pub upper_bound: f64,
+    /// The best first-stage solution found, empty if no feasible one was found.
+    pub first_stage: Vec<f64>,
+    /// The progress of every iteration.
+    pub iterations: Vec<BendersIteration>,
+}
+
+/// Benders decomposition of a two-stage LP `min c^T x + sum(p_s Q_s(x))` over the rows of the master,
+/// where `Q_s(x)` is the optimal value of the subproblem of scenario `s`.
+///
+/// Each iteration solves the master and then all subproblems for its first-stage solution, in
+/// parallel and warm started from their previous bases. Optimal subproblems yield optimality cuts
+/// from their dual solutions, either one per scenario (multi-cut) or one aggregated cut. The C
+/// interface of SoPlex does not expose Farkas rays, so infeasible subproblems yield feasibility cuts
+/// from the dual solution of their elastic copy that minimizes the violation of their rows. Both
+/// stages are minimized, the objective senses are set by the driver.
+///
+/// # Example
+/// ```
+/// use soplex_rs::*;
+///
+/// // buy x now at cost 1 or y later at cost 2 to cover a demand of 3
+/// let mut master = Model::new();
+/// master.add_col(vec![], 1.0, 0.0, 10.0);
+/// let mut sub = Model::new();
+/// sub.add_col(vec![], 2.0, 0.0, f64::INFINITY);
+/// sub.add_row(vec![1.0], 3.0, f64::INFINITY);
+///
+/// let scenario = Scenario::new(sub, vec![(0, 0, 1.0)], 1.0);
+/// let result = Benders::new(master, vec![scenario]).run();
+/// assert_eq!(result.status, BendersStatus::Converged);
+/// assert!((result.upper_bound - 3.0).abs() < 1e-6);
+/// ```
+pub struct Benders {
+    master: Model,
+    scenarios: Vec<Scenario>,
+    multi_cut: bool,
+    max_iterations: usize,
+    gap_tol: f64,
+    threads: usize,
+}
+
+/// The outcome of solving a subproblem for a first-stage solution.
+enum Outcome {
+    /// The optimal value and its subgradient with respect to the first-stage columns.
+    Optimal(f64, Vec<f64>),
+    /// The minimal total violation of the rows and its subgradient.
+    Infeasible(f64, Vec<f64>),
+    /// The subproblem (or its elastic copy) ended with another status.
+    Failed(Status),
+}
+
+/// A copy of a scenario subproblem that `Benders::run` creates and owns.
+struct Subproblem(Model);
+
+// SAFETY: the SoPlex instance of a subproblem is created by `Benders::run` and never handed out, so
+// it is only used by the one thread that currently owns the subproblem. SoPlex instances share no
+// mutable global state: its only thread-local variable is a constant, and the timers, the random
+// number generator and the message output are members of the instance.
+unsafe impl Send for Subproblem {}
+
+/// The data of a scenario needed to shift its rows and to derive cuts.
+struct Linking<'a> {
+    ranges: Vec<(f64, f64)>,
+    infinity: f64,
+    technology: &'a [(RowId, ColId, f64)],
+    num_master_cols: usize,
+}
+
+impl Linking<'_> {
+    /// Returns the subgradient `-T^T y` of the subproblem value for the duals `y` of its rows.
+    fn gradient(&self, duals: &[f64]) -> Vec<f64> {
+        let mut gradient = vec![0.0; self.num_master_cols];
+        for &(row_id, col_id, value) in self.technology {
+            gradient[col_id] -= duals[row_id] * value;
+        }
+        gradient
+    }
+
+    /// Shifts the rows of the subproblem to the first-stage solution and solves it.
+    fn solve(&self, mut model: Model, x: &[f64]) -> (Model, Outcome) {
+        let mut shift = vec![0.0; self.ranges.len()];
+        for &(row_id, col_id, value) in self.technology {
+            shift[row_id] += value * x[col_id];
+        }
+        for (row_id, (&(lhs, rhs), shift)) in self.ranges.iter().zip(shift).enumerate() {
+            let lhs = if lhs.abs() < self.infinity {
+                lhs - shift
+            } else {
+                lhs
+            };
+            let rhs = if rhs.abs() < self.infinity {
+                rhs - shift
+            } else {
+                rhs
+            };
+            model.change_row_range(row_id, lhs, rhs);
+        }
+
+        let solved = model.optimize();
+        let outcome = match solved.status() {
+            Status::Optimal => {
+                Outcome::Optimal(solved.obj_val(), self.gradient(&solved.dual_solution()))
+            }
+            Status::Infeasible => {
+                // only the rows depend on x, the bounds of the subproblem stay hard
+                let elastic = Elastic::new(solved.lp_data(), |origin, _| match origin {
+                    Origin::Row(_) => Some(1.0),
+                    Origin::Bounds(_) => None,
+                });
+                let phase_one = elastic.model.optimize();
+                match phase_one.status() {
+                    Status::Optimal => Outcome::Infeasible(
+                        phase_one.obj_val(),
+                        self.gradient(&phase_one.dual_solution()),
+                    ),
+                    status => Outcome::Failed(status),
+                }
+            }
+            status => Outcome::Failed(status),
+        };
+        (Model::from(solved), outcome)
+    }
+}
+
+impl Benders {
+    /// Creates the decomposition.
+    ///
+    /// # Arguments
+    /// * `master` - The master with the first-stage columns and rows, which are never removed.
+    /// * `scenarios` - The scenario subproblems.
+    pub fn new(master: Model, scenarios: Vec<Scenario>) -> Self {
+        Self {
+            master,
+            scenarios,
+            multi_cut: true,
+            max_iterations: usize::MAX,
+            gap_tol: 1e-6,
+            threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    /// Whether every scenario gets its own cost-to-go column and optimality cuts, `true` by default.
+    /// Otherwise the optimality cuts are aggregated into one cut per iteration.
+    pub fn with_multi_cut(mut self, multi_cut: bool) -> Self {
+        self.multi_cut = multi_cut;
+        self
+    }
+
+    /// Sets the maximum number of iterations, unlimited by default.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Sets the relative gap between the bounds at which the decomposition stops, 1e-6 by default.
+    pub fn with_gap_tol(mut self, gap_tol: f64) -> Self {
+        self.gap_tol = gap_tol;
+        self
+    }
+
+    /// Sets the number of threads the subproblems are solved on, the available parallelism by default.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Runs the decomposition until it converges or a limit is reached.
+    pub fn run(self) -> BendersResult {
+        let mut master = self.master;
+        master.set_obj_sense(ObjSense::Minimize);
+        let cost = master.lp_data().obj;
+        let num_master_cols = cost.len();
+
+        let mut models = Vec::with_capacity(self.scenarios.len());
+        let mut technologies = Vec::with_capacity(self.scenarios.len());
+        let mut probabilities = Vec::with_capacity(self.scenarios.len());
+        for scenario in self.scenarios {
+            assert!(
+                scenario
+                    .technology
+                    .iter()
+                    .all(|&(_, col_id, _)| col_id < num_master_cols),
+                "technology refers to a column that is not part of the master"
+            );
+            // only copies that never leave the driver are solved on other threads
+            let data = scenario.model.lp_data();
+            let mut model = Model::load(
+                &data.obj,
+                &data.col_bounds,
+                &data.row_ranges,
+                true,
+                data.rows,
+            );
+            model.set_parameters(&scenario.model.parameters());
+            model.set_obj_sense(ObjSense::Minimize);
+            models.push(Subproblem(model));
+            technologies.push(scenario.technology);
+            probabilities.push(scenario.probability);
+        }
+        let linkings: Vec<Linking> = models
+            .iter()
+            .zip(&technologies)
+            .map(|(Subproblem(model), technology)| {
+                let data = model.lp_data();
+                Linking {
+                    ranges: data.row_ranges,
+                    infinity: data.infinity,
+                    technology,
+                    num_master_cols,
+                }
+            })
+            .collect();
+
+        // the cost-to-go columns, one per scenario or one for all of them
+        let num_thetas = if self.multi_cut { models.len() } else { 1 };
+        let mut thetas: Vec<Option<ColId>> = vec![None; num_thetas];
+        let mut iterations = Vec::new();
+        let mut lower_bound = f64::NEG_INFINITY;
+        let mut upper_bound = f64::INFINITY;
+        let mut first_stage = Vec::new();
+
+        let (status, master) = loop {
+            let solved = master.optimize();
+            let status = solved.status();
+            if status != Status::Optimal {
+                break (BendersStatus::MasterNotOptimal(status), solved);
+            }
+
+            let primal = solved.primal_solution();
+            let x = &primal[..num_master_cols];
+            let (solved_models, outcomes) =
+                solve_all(std::mem::take(&mut models), &linkings, x, self.threads);
+            models = solved_models;
+            let failed = outcomes
+                .iter()
+                .enumerate()
+                .find_map(|(idx, outcome)| match outcome {
+                    Outcome::Failed(status) => Some((idx, status.clone())),
+                    _ => None,
+                });
+            if let Some((idx, status)) = failed {
+                break (BendersStatus::SubproblemNotOptimal(idx, status), solved);
+            }
+
+            if thetas.iter().all(Option::is_some) {
+                lower_bound = lower_bound.max(solved.obj_val());
+            }
+            // a cut is `lhs <= coefs^T x + theta <= rhs` for the cost-to-go column with the given index
+            let mut cuts: Vec<(Option<usize>, Vec<f64>, f64, f64)> = Vec::new();
+            let is_violated = |theta: Option<ColId>, value: f64| match theta {
+                Some(col_id) => value - primal[col_id] > CUT_TOL * (1.0 + value.abs()),
+                None => true,
+            };
+            let dot =
+                |gradient: &[f64]| -> f64 { gradient.iter().zip(x).map(|(g, x)| g * x).sum() };
+            let mut value = cost.iter().zip(x).map(|(c, x)| c * x).sum::<f64>();
+            let mut feasible = true;
+            let mut aggregated = (0.0, vec![0.0; num_master_cols]);
+            let mut num_feasibility_cuts = 0;
+            for (idx, outcome) in outcomes.into_iter().enumerate() {
+                match outcome {
+                    Outcome::Optimal(obj_val, gradient) => {
+                        let probability = probabilities[idx];
+                        value += probability * obj_val;
+                        if self.multi_cut {
+                            if is_violated(thetas[idx], obj_val) {
+                                let lhs = obj_val - dot(&gradient);
+                                let coefs = gradient.iter().map(|g| -g).collect();
+                                cuts.push((Some(idx), coefs, lhs, f64::INFINITY));
+                            }
+                        } else {
+                            aggregated.0 += probability * obj_val;
+                            for (sum, g) in aggregated.1.iter_mut().zip(gradient) {
+                                *sum += probability * g;
+                            }
+                        }
+                    }
+                    Outcome::Infeasible(violation, gradient) => {
+                        // violation + gradient^T (x - x_hat) <= 0 has to hold for a feasible x
+                        feasible = false;
+                        let rhs = dot(&gradient) - violation;
+                        cuts.push((None, gradient, f64::NEG_INFINITY, rhs));
+                        num_feasibility_cuts += 1;
+                    }
+                    Outcome::Failed(_) => unreachable!("failed subproblems are handled above"),
+                }
+            }
+            if !self.multi_cut && feasible && is_violated(thetas[0], aggregated.0) {
+                let (obj_val, gradient) = aggregated;
+                let lhs = obj_val - dot(&gradient);
+                let coefs = gradient.iter().map(|g| -g).collect();
+                cuts.push((Some(0), coefs, lhs, f64::INFINITY));
+            }
+
+            if feasible && value < upper_bound {
+                upper_bound = value;
+                first_stage = x.to_vec();
+            }
+            iterations.push(BendersIteration {
+                lower_bound,
+                upper_bound: if feasible { value } else { f64::INFINITY },
+                num_optimality_cuts: cuts.len() - num_feasibility_cuts,
+                num_feasibility_cuts,
+            });
+
+            let gap_closed = upper_bound.is_finite()
+                && upper_bound - lower_bound <= self.gap_tol * (1.0 + upper_bound.abs());
+            if cuts.is_empty() || gap_closed {
+                break (BendersStatus::Converged, solved);
+            }
+            if iterations.len() >= self.max_iterations {
+                break (BendersStatus::IterationLimit, solved);
+            }
+
+            master = Model::from(solved);
+            for (theta, coefs, lhs, rhs) in cuts {
+                let mut row = coefs;
+                if let Some(idx) = theta {
+                    let weight = if self.multi_cut {
+                        probabilities[idx]
+                    } else {
+                        1.0
+                    };
+                    let num_rows = master.num_rows();
+                    let col_id = *thetas[idx].get_or_insert_with(|| {
+                        master.add_col(
+                            vec![0.0; num_rows],
+                            weight,
+                            f64::NEG_INFINITY,
+                            f64::INFINITY,
+                        )
+                    });
+                    row.resize(master.num_cols(), 0.0);
+                    row[col_id] = 1.0;
+                } else {
+                    row.resize(master.num_cols(), 0.0);
+                }
+                master.add_row(row, lhs, rhs);
+            }
+        };
+
+        BendersResult {
+            status,
+            master,
+            lower_bound,
+            upper_bound,
+            first_stage,
+            iterations,
+        }
+    }
+}
+
+/// Solves the subproblems for a first-stage solution, spread evenly over the given number of threads.
+fn solve_all(
+    models: Vec<Subproblem>,
+    linkings: &[Linking],
+    x: &[f64],
+    threads: usize,
+) -> (Vec<Subproblem>, Vec<Outcome>) {
+    let chunk_size = models.len().div_ceil(threads).max(1);
+    let mut jobs = models.into_iter().zip(linkings).peekable();
+    let mut chunks = Vec::new();
+    while jobs.peek().is_some() {
+        chunks.push(jobs.by_ref().take(chunk_size).collect::<Vec<_>>());
+    }
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .into_iter()
+                        .map(|(Subproblem(model), linking)| {
+                            let (model, outcome) = linking.solve(model, x);
+                            (Subproblem(model), outcome)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("subproblem thread panicked"))
+            .unzip()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Buy `x` now at cost 1 or `y` later at cost 2 to cover a demand of 3 or 5, equally likely.
+    fn newsvendor() -> (Model, Vec<Scenario>) {
+        let mut master = Model::new();
+        master.add_col(vec![], 1.0, 0.0, 10.0);
+        let scenarios = [3.0, 5.0]
+            .into_iter()
+            .map(|demand| {
+                let mut sub = Model::new();
+                sub.add_col(vec![], 2.0, 0.0, f64::INFINITY);
+                sub.add_row(vec![1.0], demand, f64::INFINITY);
+                Scenario::new(sub, vec![(0, 0, 1.0)], 0.5)
+            })
+            .collect();
+        (master, scenarios)
+    }
+
+    #[test]
+    fn benders_multi_cut() {
+        let (master, scenarios) = newsvendor();
+        let result = Benders::new(master, scenarios).run();
+        assert_eq!(result.status, BendersStatus::Converged);
+        assert!((result.upper_bound - 5.0).abs() < 1e-6);
+        assert!((result.lower_bound - 5.0).abs() < 1e-6);
+        assert!(result.first_stage[0] >= 3.0 - 1e-6 && result.first_stage[0] <= 5.0 + 1e-6);
+        assert_eq!(result.master.num_cols(), 3);
+    }
+
+    #[test]
+    fn benders_single_cut() {
+        let (master, scenarios) = newsvendor();
+        let result = Benders::new(master, scenarios)
+            .with_multi_cut(false)
+            .with_threads(1)
+            .run();
+        assert_eq!(result.status, BendersStatus::Converged);
+        assert!((result.upper_bound - 5.0).abs() < 1e-6);
+        assert_eq!(result.master.num_cols(), 2);
+        assert!(result
+            .iterations
+            .iter()
+            .all(|it| it.lower_bound <= 5.0 + 1e-6));
+    }
+
+    #[test]
+    fn benders_feasibility_cuts() {
+        // y <= 1 covers the demand of 4 only if x >= 3
+        let mut master = Model::new();
+        master.add_col(vec![], 1.0, 0.0, 10.0);
+        let mut sub = Model::new();
+        sub.add_col(vec![], 1.0, 0.0, 1.0);
+        sub.add_row(vec![1.0], 4.0, f64::INFINITY);
+        let scenario = Scenario::new(sub, vec![(0, 0, 1.0)], 1.0);
+
+        let result = Benders::new(master, vec![scenario]).run();
+        assert_eq!(result.status, BendersStatus::Converged);
+        assert!((result.upper_bound - 4.0).abs() < 1e-6);
+        assert!(result.first_stage[0] >= 3.0 - 1e-6);
+        assert!(result.iterations[0].num_feasibility_cuts == 1);
+        assert_eq!(result.iterations[0].upper_bound, f64::INFINITY);
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{ColBasisStatus, ColId, Model, ObjSense, RowId, SolvedModel, Status};
+
+/// Reduced costs closer to zero than this do not improve the master.
+const REDCOST_TOL: f64 = 1e-9;
+
+/// A column proposed by a `PricingOracle`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewColumn {
+    /// The nonzero entries of the column.
+    pub entries: Vec<(RowId, f64)>,
+    /// The objective value of the column.
+    pub obj: f64,
+    /// The lower bound of the column.
+    pub lb: f64,
+    /// The upper bound of the column.
+    pub ub: f64,
+}
+
+impl NewColumn {
+    /// Creates a nonnegative column.
+    ///
+    /// # Arguments
+    /// * `entries` - The nonzero entries of the column.
+    /// * `obj` - The objective value of the column.
+    pub fn new(entries: Vec<(RowId, f64)>, obj: f64) -> Self {
+        Self {
+            entries,
+            obj,
+            lb: 0.0,
+            ub: f64::INFINITY,
+        }
+    }
+
+    /// Returns the reduced cost of the column for the given dual solution.
+    pub fn reduced_cost(&self, duals: &[f64]) -> f64 {
+        self.obj
+            - self
+                .entries
+                .iter()
+                .map(|&(row_id, value)| duals[row_id] * value)
+                .sum::<f64>()
+    }
+}
+
+/// Pricing problem of a column generation, see `ColumnGeneration`.
+pub trait PricingOracle {
+    /// Returns columns for the dual solution of the restricted master problem.
+    ///
+    /// Columns that do not have an improving reduced cost are ignored. To get a valid Lagrangian
+    /// bound, the column with the best reduced cost has to be among the returned ones.
+    fn price(&self, duals: &[f64]) -> Vec<NewColumn>;
+}
+
+/// Why a `ColumnGeneration` stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColGenStatus {
+    /// The oracle found no improving column, the master is optimal.
+    Converged,
+    /// The Lagrangian bound is within the gap tolerance of the master objective.
+    GapClosed,
+    /// The iteration limit was reached.
+    IterationLimit,
+    /// The restricted master problem could not be solved to optimality.
+    MasterNotOptimal(Status),
+}
+
+/// Progress of one iteration of a `ColumnGeneration`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ColGenIteration {
+    /// Objective value of the restricted master problem.
+    pub obj_val: f64,
+    /// Lagrangian bound on the objective of the full master problem, if `kappa` is set.
+    pub lagrangian_bound: Option<f64>,
+    /// Number of columns added after the iteration.
+    pub num_added: usize,
+    /// Number of aged columns removed after the iteration.
+    pub num_removed: usize,
+}
+
+/// Result of `ColumnGeneration::run`.
+#[non_exhaustive]
+pub struct ColGenResult {
+    /// Why the column generation stopped.
+    pub status: ColGenStatus,
+    /// The last solved restricted master problem.
+    pub model: SolvedModel,
+    /// The progress of every iteration.
+    pub iterations: Vec<ColGenIteration>,
+    /// All columns that were added, in order.
+    pub columns: Vec<NewColumn>,
+    /// For every column of `model`, the index into `columns` or `None` for the initial columns.
+    pub origins: Vec<Option<usize>>,
+}
+
+/// Column generation driver around a restricted master problem and a pricing oracle.
+///
+/// Each iteration solves the master warm started from the previous basis, passes its dual solution
+/// to the oracle and adds the columns with an improving reduced cost. Generated columns that have
+/// been nonbasic for more than `max_age` iterations are removed again.
+///
+/// # Example
+/// ```
+/// use soplex_rs::*;
+///
+/// struct Pool(Vec<NewColumn>);
+///
+/// impl PricingOracle for Pool {
+///     fn price(&self, duals: &[f64]) -> Vec<NewColumn> {
+///         self.0.iter().filter(|col| col.reduced_cost(duals) < 0.0).cloned().collect()
+///     }
+/// }
+///
+/// // cover the row x0 + x1 >= 2 as cheaply as possible
+/// let mut master = Model::new();
+/// master.add_col(vec![], 10.0, 0.0, f64::INFINITY);
+/// master.add_row(vec![1.0], 2.0, f64::INFINITY);
+/// master.set_obj_sense(ObjSense::Minimize);
+///
+/// let pool = Pool(vec![NewColumn::new(vec![(0, 1.0)], 3.0)]);
+/// let result = ColumnGeneration::new(master, pool).run();
+/// assert_eq!(result.status, ColGenStatus::Converged);
+/// assert!((result.model.obj_val() - 6.0).abs() < 1e-6);
+/// ```
+pub struct ColumnGeneration<O> {
+    master: Model,
+    oracle: O,
+    max_iterations: usize,
+    max_age: Option<usize>,
+    kappa: Option<f64>,
+    gap_tol: f64,
+}
+
+impl<O: PricingOracle> ColumnGeneration<O> {
+    /// Creates the driver.
+    ///
+    /// # Arguments
+    /// * `master` - The restricted master problem with its initial columns, which are never removed.
+    /// * `oracle` - The pricing oracle.
+    pub fn new(master: Model, oracle: O) -> Self {
+        Self {
+            master,
+            oracle,
+            max_iterations: usize::MAX,
+            max_age: None,
+            kappa: None,
+            gap_tol: 1e-6,
+        }
+    }
+
+    /// Sets the maximum number of iterations, unlimited by default.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Removes generated columns that have been nonbasic for more than `max_age` iterations,
+    /// columns are never removed by default.
+    pub fn with_max_age(mut self, max_age: usize) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Enables the Lagrangian bound `obj_val + kappa * best_reduced_cost`, where `kappa` bounds
+    /// the sum of the generated columns in an optimal solution, e.g. the number of available crews.
+    pub fn with_kappa(mut self, kappa: f64) -> Self {
+        self.kappa = Some(kappa);
+        self
+    }
+
+    /// Sets the relative gap between the objective and the Lagrangian bound at which the column
+    /// generation stops, 1e-6 by default.
+    pub fn with_gap_tol(mut self, gap_tol: f64) -> Self {
+        self.gap_tol = gap_tol;
+        self
+    }
+
+    /// Runs the column generation until it converges or a limit is reached.
+    pub fn run(self) -> ColGenResult {
+        let mut model = self.master;
+        let minimize = model.obj_sense() == ObjSense::Minimize;
+        let mut ages = vec![0; model.num_cols()];
+        let mut origins = vec![None; model.num_cols()];
+        let mut columns = Vec::new();
+        let mut iterations = Vec::new();
+
+        let (status, model) = loop {
+            let solved = model.optimize();
+            let status = solved.status();
+            if status != Status::Optimal {
+                break (ColGenStatus::MasterNotOptimal(status), solved);
+            }
+
+            let obj_val = solved.obj_val();
+            let duals = solved.dual_solution();
+            for (col_id, age) in ages.iter_mut().enumerate() {
+                if solved.col_basis_status(col_id) == ColBasisStatus::Basic {
+                    *age = 0;
+                } else {
+                    *age += 1;
+                }
+            }
+
+            // a reduced cost improves if it is negative when minimizing, positive when maximizing
+            let sign = if minimize { 1.0 } else { -1.0 };
+            let improving: Vec<NewColumn> = self
+                .oracle
+                .price(&duals)
+                .into_iter()
+                .filter(|col| sign * col.reduced_cost(&duals) < -REDCOST_TOL)
+                .collect();
+            let best = improving
+                .iter()
+                .map(|col| sign * col.reduced_cost(&duals))
+                .fold(0.0, f64::min);
+            let lagrangian_bound = self.kappa.map(|kappa| obj_val + sign * kappa * best);
+            iterations.push(ColGenIteration {
+                obj_val,
+                lagrangian_bound,
+                num_added: improving.len(),
+                num_removed: 0,
+            });
+
+            if improving.is_empty() {
+                break (ColGenStatus::Converged, solved);
+            }
+            if let Some(bound) = lagrangian_bound {
+                if (obj_val - bound).abs() <= self.gap_tol * (1.0 + obj_val.abs()) {
+                    break (ColGenStatus::GapClosed, solved);
+                }
+            }
+            if iterations.len() >= self.max_iterations {
+                break (ColGenStatus::IterationLimit, solved);
+            }
+
+            model = Model::from(solved);
+            let num_rows = model.num_rows();
+            let old_cols = model.num_cols();
+            for col in improving {
+                let mut dense = vec![0.0; num_rows];
+                for &(row_id, value) in &col.entries {
+                    assert!(
+                        row_id < num_rows,
+                        "column refers to a row that is not part of the master"
+                    );
+                    dense[row_id] += value;
+                }
+                model.add_col(dense, col.obj, col.lb, col.ub);
+                ages.push(0);
+                origins.push(Some(columns.len()));
+                columns.push(col);
+            }
+
+            if let Some(max_age) = self.max_age {
+                let aged: Vec<ColId> = (0..old_cols)
+                    .rev()
+                    .filter(|&col_id| origins[col_id].is_some() && ages[col_id] > max_age)
+                    .collect();
+                // removing a column moves the last column into its place
+                for &col_id in &aged {
+                    model.remove_col(col_id);
+                    ages.swap_remove(col_id);
+                    origins.swap_remove(col_id);
+                }
+                iterations.last_mut().expect("pushed above").num_removed = aged.len();
+            }
+        };
+
+        ColGenResult {
+            status,
+            model,
+            iterations,
+            columns,
+            origins,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cutting stock with rolls of width 10, 4 items of width 3 and 2 items of width 5.
+    struct Patterns;
+
+    impl PricingOracle for Patterns {
+        fn price(&self, duals: &[f64]) -> Vec<NewColumn> {
+            // the pattern with the best reduced cost
+            (0..=3)
+                .flat_map(|a| (0..=2).map(move |b| (a, b)))
+                .filter(|&(a, b)| 3 * a + 5 * b <= 10 && a + b > 0)
+                .map(|(a, b)| NewColumn::new(vec![(0, a as f64), (1, b as f64)], 1.0))
+                .min_by(|x, y| x.reduced_cost(duals).total_cmp(&y.reduced_cost(duals)))
+                .into_iter()
+                .collect()
+        }
+    }
+
+    fn master() -> Model {
+        let mut master = Model::new();
+        master.add_col(vec![], 1.0, 0.0, f64::INFINITY);
+        master.add_col(vec![], 1.0, 0.0, f64::INFINITY);
+        master.add_row(vec![1.0, 0.0], 4.0, f64::INFINITY);
+        master.add_row(vec![0.0, 1.0], 2.0, f64::INFINITY);
+        master.set_obj_sense(ObjSense::Minimize);
+        master
+    }
+
+    #[test]
+    fn column_generation() {
+        let result = ColumnGeneration::new(master(), Patterns)
+            .with_kappa(10.0)
+            .run();
+        assert_eq!(result.status, ColGenStatus::Converged);
+        assert!((result.model.obj_val() - 7.0 / 3.0).abs() < 1e-6);
+        assert_eq!(result.origins.len(), result.model.num_cols());
+        assert_eq!(result.columns.len(), result.model.num_cols() - 2);
+        for it in &result.iterations {
+            assert!(it.lagrangian_bound.unwrap() <= 7.0 / 3.0 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn column_generation_aging() {
+        let result = ColumnGeneration::new(master(), Patterns)
+            .with_max_age(0)
+            .run();
+        assert_eq!(result.status, ColGenStatus::Converged);
+        assert!((result.model.obj_val() - 7.0 / 3.0).abs() < 1e-6);
+        assert_eq!(result.origins.len(), result.model.num_cols());
+        let removed: usize = result.iterations.iter().map(|it| it.num_removed).sum();
+        assert_eq!(result.model.num_cols(), 2 + result.columns.len() - removed);
+        // the remaining generated columns are the ones the origins point to
+        let primal = result.model.primal_solution();
+        for (col_id, origin) in result.origins.iter().enumerate() {
+            if let Some(idx) = origin {
+                assert_eq!(result.columns[*idx].obj, 1.0);
+                assert!(primal[col_id] >= -1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn column_generation_iteration_limit() {
+        let result = ColumnGeneration::new(master(), Patterns)
+            .with_max_iterations(1)
+            .run();
+        assert_eq!(result.status, ColGenStatus::IterationLimit);
+        assert_eq!(result.iterations.len(), 1);
+        assert!((result.iterations[0].obj_val - 6.0).abs() < 1e-6);
+    }
+}
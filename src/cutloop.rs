@@ -0,0 +1,330 @@
+use crate::{Algorithm, ColId, Model, RowBasisStatus, RowId, SolvedModel, Status};
+
+/// Violations up to this value are tolerated when cuts from the pool are checked.
+const VIOLATION_TOL: f64 = 1e-9;
+
+/// A cut `lhs <= sum(value * x[col_id]) <= rhs` returned by a `Separator`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cut {
+    /// The nonzero entries of the cut.
+    pub entries: Vec<(ColId, f64)>,
+    /// The left-hand side of the cut.
+    pub lhs: f64,
+    /// The right-hand side of the cut.
+    pub rhs: f64,
+}
+
+impl Cut {
+    /// Creates the cut `sum(value * x[col_id]) <= rhs`.
+    pub fn le(entries: Vec<(ColId, f64)>, rhs: f64) -> Self {
+        Self {
+            entries,
+            lhs: f64::NEG_INFINITY,
+            rhs,
+        }
+    }
+
+    /// Creates the cut `sum(value * x[col_id]) >= lhs`.
+    pub fn ge(entries: Vec<(ColId, f64)>, lhs: f64) -> Self {
+        Self {
+            entries,
+            lhs,
+            rhs: f64::INFINITY,
+        }
+    }
+
+    /// Returns by how much the cut is violated by a primal solution, 0 if it is satisfied.
+    pub fn violation(&self, primal: &[f64]) -> f64 {
+        let activity: f64 = self
+            .entries
+            .iter()
+            .map(|&(col_id, value)| primal[col_id] * value)
+            .sum();
+        (self.lhs - activity).max(activity - self.rhs).max(0.0)
+    }
+}
+
+/// Separation problem of a cutting plane loop, see `CutLoop`.
+pub trait Separator {
+    /// Returns cuts violated by the primal solution of the current LP, none if there are no more.
+    fn separate(&mut self, primal: &[f64]) -> Vec<Cut>;
+}
+
+/// Why a `CutLoop` stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CutLoopStatus {
+    /// Neither the separator nor the cut pool found a violated cut.
+    NoCuts,
+    /// The objective did not move by more than the stall tolerance over the last rounds.
+    Stalled,
+    /// The round limit was reached.
+    RoundLimit,
+    /// The LP could not be solved to optimality.
+    NotOptimal(Status),
+}
+
+/// Progress of one round of a `CutLoop`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CutRound {
+    /// Objective value of the LP at the start of the round.
+    pub obj_val: f64,
+    /// Number of cuts added in the round, including cuts taken from the pool.
+    pub num_added: usize,
+    /// Number of inactive cuts removed in the round.
+    pub num_removed: usize,
+}
+
+/// Result of `CutLoop::run`.
+#[non_exhaustive]
+pub struct CutLoopResult {
+    /// Why the loop stopped.
+    pub status: CutLoopStatus,
+    /// The last solved LP.
+    pub model: SolvedModel,
+    /// The progress of every round.
+    pub rounds: Vec<CutRound>,
+    /// The cut pool, all cuts returned by the separator in order.
+    pub cuts: Vec<Cut>,
+    /// For every row of `model`, the index into `cuts` or `None` for the original rows.
+    pub origins: Vec<Option<usize>>,
+}
+
+/// Cutting plane loop around a model and a separator.
+///
+/// Each round solves the LP with the dual simplex, warm started from the previous basis, and adds
+/// the cuts returned by the separator together with the cuts from the pool that are violated again.
+/// Cuts whose row has been basic (inactive) for `max_basic_rounds` rounds are removed from the LP
+/// but stay in the pool.
+pub struct CutLoop<S> {
+    model: Model,
+    separator: S,
+    max_rounds: usize,
+    max_basic_rounds: Option<usize>,
+    stall_rounds: usize,
+    stall_tol: f64,
+}
+
+impl<S: Separator> CutLoop<S> {
+    /// Creates the cutting plane loop.
+    ///
+    /// # Arguments
+    /// * `model` - The LP, its rows are never removed.
+    /// * `separator` - The separator.
+    pub fn new(model: Model, separator: S) -> Self {
+        Self {
+            model,
+            separator,
+            max_rounds: usize::MAX,
+            max_basic_rounds: None,
+            stall_rounds: 3,
+            stall_tol: 1e-6,
+        }
+    }
+
+    /// Sets the maximum number of rounds, unlimited by default.
+    pub fn with_max_rounds(mut self, max_rounds: usize) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    /// Removes cuts that have been basic for `max_basic_rounds` rounds in a row, cuts are never
+    /// removed by default.
+    pub fn with_max_basic_rounds(mut self, max_basic_rounds: usize) -> Self {
+        self.max_basic_rounds = Some(max_basic_rounds);
+        self
+    }
+
+    /// Stops when the objective moved by at most `stall_tol` (relative) over the last `stall_rounds`
+    /// rounds, 3 rounds and 1e-6 by default. A `stall_rounds` of 0 disables the check.
+    pub fn with_stalling(mut self, stall_rounds: usize, stall_tol: f64) -> Self {
+        self.stall_rounds = stall_rounds;
+        self.stall_tol = stall_tol;
+        self
+    }
+
+    /// Runs the cutting plane loop until no violated cuts are found or it stops early.
+    pub fn run(mut self) -> CutLoopResult {
+        let mut model = self.model;
+        model.set_algorithm(Algorithm::Dual);
+        let mut basic_rounds = vec![0; model.num_rows()];
+        let mut origins = vec![None; model.num_rows()];
+        let mut cuts: Vec<Cut> = Vec::new();
+        let mut rounds: Vec<CutRound> = Vec::new();
+
+        let (status, model) = loop {
+            let solved = model.optimize();
+            let status = solved.status();
+            if status != Status::Optimal {
+                break (CutLoopStatus::NotOptimal(status), solved);
+            }
+
+            let obj_val = solved.obj_val();
+            let stall_round = match self.stall_rounds {
+                0 => None,
+                stall_rounds => rounds.len().checked_sub(stall_rounds),
+            };
+            if let Some(old) = stall_round {
+                let old = rounds[old].obj_val;
+                if (obj_val - old).abs() <= self.stall_tol * (1.0 + obj_val.abs()) {
+                    break (CutLoopStatus::Stalled, solved);
+                }
+            }
+            if rounds.len() >= self.max_rounds {
+                break (CutLoopStatus::RoundLimit, solved);
+            }
+            for (row_id, count) in basic_rounds.iter_mut().enumerate() {
+                if solved.row_basis_status(row_id) == RowBasisStatus::Basic {
+                    *count += 1;
+                } else {
+                    *count = 0;
+                }
+            }
+
+            // cuts from the pool that are not in the LP but violated again, then new ones
+            let primal = solved.primal_solution();
+            let mut in_lp = vec![false; cuts.len()];
+            for &idx in origins.iter().flatten() {
+                in_lp[idx] = true;
+            }
+            let mut added: Vec<usize> = (0..cuts.len())
+                .filter(|&idx| !in_lp[idx] && cuts[idx].violation(&primal) > VIOLATION_TOL)
+                .collect();
+            for cut in self.separator.separate(&primal) {
+                added.push(cuts.len());
+                cuts.push(cut);
+            }
+            rounds.push(CutRound {
+                obj_val,
+                num_added: added.len(),
+                num_removed: 0,
+            });
+            if added.is_empty() {
+                break (CutLoopStatus::NoCuts, solved);
+            }
+
+            model = Model::from(solved);
+            let num_cols = model.num_cols();
+            let old_rows = model.num_rows();
+            for idx in added {
+                let cut = &cuts[idx];
+                let mut dense = vec![0.0; num_cols];
+                for &(col_id, value) in &cut.entries {
+                    assert!(
+                        col_id < num_cols,
+                        "cut refers to a column that is not part of the model"
+                    );
+                    dense[col_id] += value;
+                }
+                model.add_row(dense, cut.lhs, cut.rhs);
+                basic_rounds.push(0);
+                origins.push(Some(idx));
+            }
+
+            if let Some(max_basic_rounds) = self.max_basic_rounds {
+                let inactive: Vec<RowId> = (0..old_rows)
+                    .rev()
+                    .filter(|&row_id| {
+                        origins[row_id].is_some() && basic_rounds[row_id] >= max_basic_rounds
+                    })
+                    .collect();
+                // removing a row moves the last row into its place
+                for &row_id in &inactive {
+                    model.remove_row(row_id);
+                    basic_rounds.swap_remove(row_id);
+                    origins.swap_remove(row_id);
+                }
+                rounds.last_mut().expect("pushed above").num_removed = inactive.len();
+            }
+        };
+
+        CutLoopResult {
+            status,
+            model,
+            rounds,
+            cuts,
+            origins,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ObjSense;
+
+    /// Returns a fixed sequence of cuts, one batch per round.
+    struct Batches(Vec<Vec<Cut>>);
+
+    impl Separator for Batches {
+        fn separate(&mut self, primal: &[f64]) -> Vec<Cut> {
+            if self.0.is_empty() {
+                return Vec::new();
+            }
+            self.0
+                .remove(0)
+                .into_iter()
+                .filter(|cut| cut.violation(primal) > 0.0)
+                .collect()
+        }
+    }
+
+    fn model() -> Model {
+        // max 2x + y  s.t.  y <= 1.5, 0 <= x <= 1.5, 0 <= y <= 10
+        let mut lp = Model::new();
+        lp.add_col(vec![], 2.0, 0.0, 1.5);
+        lp.add_col(vec![], 1.0, 0.0, 10.0);
+        lp.add_row(vec![0.0, 1.0], f64::NEG_INFINITY, 1.5);
+        lp.set_obj_sense(ObjSense::Maximize);
+        lp
+    }
+
+    fn batches() -> Batches {
+        Batches(vec![
+            vec![Cut::le(vec![(0, 1.0), (1, 1.0)], 2.5)],
+            vec![Cut::le(vec![(0, 1.0), (1, 1.0)], 2.0)],
+            vec![Cut::le(vec![(0, 1.0)], 1.0), Cut::ge(vec![(0, 1.0)], 0.0)],
+        ])
+    }
+
+    #[test]
+    fn cut_loop() {
+        let result = CutLoop::new(model(), batches()).run();
+        assert_eq!(result.status, CutLoopStatus::NoCuts);
+        let objs: Vec<f64> = result.rounds.iter().map(|r| r.obj_val).collect();
+        assert_eq!(objs.len(), 4);
+        for (obj, expected) in objs.iter().zip([4.5, 4.0, 3.5, 3.0]) {
+            assert!((obj - expected).abs() < 1e-6);
+        }
+        assert_eq!(result.cuts.len(), 3);
+        assert_eq!(result.origins, vec![None, Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn cut_loop_removes_inactive_cuts() {
+        let result = CutLoop::new(model(), batches())
+            .with_max_basic_rounds(1)
+            .run();
+        assert_eq!(result.status, CutLoopStatus::NoCuts);
+        assert!((result.model.obj_val() - 3.0).abs() < 1e-6);
+        // x + y <= 2.5 is inactive once x + y <= 2 is added
+        assert_eq!(result.rounds[2].num_removed, 1);
+        assert_eq!(result.origins, vec![None, Some(2), Some(1)]);
+        assert_eq!(result.model.num_rows(), 3);
+    }
+
+    #[test]
+    fn cut_loop_stalls() {
+        let result = CutLoop::new(model(), batches()).with_stalling(1, 0.2).run();
+        assert_eq!(result.status, CutLoopStatus::Stalled);
+        assert_eq!(result.rounds.len(), 1);
+        assert!((result.model.obj_val() - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cut_loop_stalling_disabled() {
+        let result = CutLoop::new(model(), batches()).with_stalling(0, 1.0).run();
+        assert_eq!(result.status, CutLoopStatus::NoCuts);
+        assert_eq!(result.rounds.len(), 4);
+    }
+}
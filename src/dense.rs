@@ -0,0 +1,86 @@
+use crate::{Model, SolvedModel};
+use ndarray::{Array1, ArrayView1, ArrayView2};
+
+impl Model {
+    /// Builds a model `b_lo <= A x <= b_hi`, `lb <= x <= ub` with objective `c` from dense arrays.
+    ///
+    /// # Arguments
+    /// * `a` - The constraint matrix, one row per constraint and one column per variable.
+    /// * `b_lo` - The left-hand side of every row.
+    /// * `b_hi` - The right-hand side of every row.
+    /// * `c` - The objective value of every column.
+    /// * `lb` - The lower bound of every column.
+    /// * `ub` - The upper bound of every column.
+    ///
+    /// The objective sense keeps its default, set it with `Model::set_obj_sense`.
+    ///
+    /// # Panics
+    /// if the lengths of the vectors do not match the shape of `a`.
+    pub fn from_dense(
+        a: ArrayView2<f64>,
+        b_lo: ArrayView1<f64>,
+        b_hi: ArrayView1<f64>,
+        c: ArrayView1<f64>,
+        lb: ArrayView1<f64>,
+        ub: ArrayView1<f64>,
+    ) -> Model {
+        let (num_rows, num_cols) = a.dim();
+        assert_eq!(b_lo.len(), num_rows, "b_lo must have one entry per row");
+        assert_eq!(b_hi.len(), num_rows, "b_hi must have one entry per row");
+        assert_eq!(c.len(), num_cols, "c must have one entry per column");
+        assert_eq!(lb.len(), num_cols, "lb must have one entry per column");
+        assert_eq!(ub.len(), num_cols, "ub must have one entry per column");
+
+        let obj = c.to_vec();
+        let col_bounds: Vec<_> = lb.iter().copied().zip(ub.iter().copied()).collect();
+        let row_ranges: Vec<_> = b_lo.iter().copied().zip(b_hi.iter().copied()).collect();
+        let rows = a.rows().into_iter().map(|row| {
+            row.iter()
+                .copied()
+                .enumerate()
+                .filter(|&(_, value)| value != 0.0)
+                .collect::<Vec<_>>()
+        });
+        Model::load(&obj, &col_bounds, &row_ranges, true, rows)
+    }
+}
+
+impl SolvedModel {
+    /// Returns the primal solution as an `Array1`.
+    pub fn primal_solution_array(&self) -> Array1<f64> {
+        Array1::from(self.primal_solution())
+    }
+
+    /// Returns the dual solution as an `Array1`.
+    pub fn dual_solution_array(&self) -> Array1<f64> {
+        Array1::from(self.dual_solution())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Model, ObjSense, Status};
+    use ndarray::array;
+
+    #[test]
+    fn from_dense() {
+        let a = array![[1.0, 1.0, 0.0], [1.0, 0.0, 1.0]];
+        let mut lp = Model::from_dense(
+            a.view(),
+            array![2.0, f64::NEG_INFINITY].view(),
+            array![f64::INFINITY, 3.0].view(),
+            array![1.0, 2.0, -1.0].view(),
+            array![0.0, 0.0, 0.0].view(),
+            array![4.0, 4.0, 2.0].view(),
+        );
+        lp.set_obj_sense(ObjSense::Minimize);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 1.0).abs() < 1e-6);
+
+        let x = lp.primal_solution_array();
+        assert_eq!(x.len(), 3);
+        assert!((a.dot(&x)[0] - 2.0).abs() < 1e-6);
+        assert_eq!(lp.dual_solution_array().len(), 2);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{ObjSense, SolvedModel};
+
+/// Sign convention of the multipliers returned by `SolvedModel::duals_normalized`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Convention {
+    /// Shadow prices `∂obj/∂side`: the change of the optimal value when the side or bound is
+    /// increased by one unit. Their sign depends on the objective sense and the side.
+    ShadowPrice,
+    /// Nonnegative multipliers: by how much the optimal value gets worse when the side or bound is
+    /// tightened by one unit, for minimization and maximization alike.
+    Tightening,
+}
+
+/// Multipliers of the sides of the rows and of the bounds of the columns, see
+/// `SolvedModel::duals_normalized`.
+///
+/// Only the active side of a row or column can have a nonzero multiplier, the other one is zero.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NormalizedDuals {
+    /// Multipliers of the left-hand sides of the rows.
+    pub lhs: Vec<f64>,
+    /// Multipliers of the right-hand sides of the rows.
+    pub rhs: Vec<f64>,
+    /// Multipliers of the lower bounds of the columns.
+    pub lower: Vec<f64>,
+    /// Multipliers of the upper bounds of the columns.
+    pub upper: Vec<f64>,
+}
+
+impl SolvedModel {
+    /// Returns the dual solution and the reduced costs as multipliers of the individual sides of
+    /// the rows and bounds of the columns, under the chosen sign convention.
+    ///
+    /// `dual_solution` and `reduced_costs` are shadow prices of whichever side is active, so a
+    /// ranged row or boxed column gets one value that has to be assigned to a side by its sign: when
+    /// minimizing a positive value belongs to the left-hand side (lower bound) and a negative one to
+    /// the right-hand side (upper bound), when maximizing it is the other way round.
+    ///
+    /// # Arguments
+    /// * `convention` - The sign convention of the returned multipliers.
+    pub fn duals_normalized(&self, convention: Convention) -> NormalizedDuals {
+        let sense = self.obj_sense();
+        let (lhs, rhs) = split(&self.dual_solution(), sense, convention);
+        let (lower, upper) = split(&self.reduced_costs(), sense, convention);
+        NormalizedDuals {
+            lhs,
+            rhs,
+            lower,
+            upper,
+        }
+    }
+}
+
+/// Splits shadow prices into the multipliers of the lower and the upper sides.
+fn split(values: &[f64], sense: ObjSense, convention: Convention) -> (Vec<f64>, Vec<f64>) {
+    // raising an active lower side makes the objective worse, i.e. larger when minimizing
+    let worse = match sense {
+        ObjSense::Minimize => 1.0,
+        ObjSense::Maximize => -1.0,
+    };
+    values
+        .iter()
+        .map(|&value| {
+            let (lower, upper) = if worse * value > 0.0 {
+                (value, 0.0)
+            } else {
+                (0.0, value)
+            };
+            match convention {
+                Convention::ShadowPrice => (lower, upper),
+                // tightening means raising a lower side and lowering an upper side
+                Convention::Tightening => (worse * lower, -worse * upper),
+            }
+        })
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Model;
+
+    /// `obj x + 2y` over `1 <= x + y <= 4`, `0 <= x <= 3`, `0 <= y <= 10`.
+    fn model(sense: ObjSense, lhs: f64, rhs: f64) -> SolvedModel {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 3.0);
+        lp.add_col(vec![], 2.0, 0.0, 10.0);
+        lp.add_row(vec![1.0, 1.0], lhs, rhs);
+        lp.set_obj_sense(sense);
+        lp.optimize()
+    }
+
+    #[test]
+    fn duals_normalized_minimize() {
+        // x = 1 at the left-hand side
+        let lp = model(ObjSense::Minimize, 1.0, 4.0);
+        let shadow = lp.duals_normalized(Convention::ShadowPrice);
+        let raised = model(ObjSense::Minimize, 1.5, 4.0).obj_val();
+        assert!((shadow.lhs[0] - (raised - lp.obj_val()) / 0.5).abs() < 1e-6);
+        assert_eq!((shadow.lhs[0], shadow.rhs[0]), (1.0, 0.0));
+        assert_eq!((shadow.lower[1], shadow.upper[1]), (1.0, 0.0));
+
+        let tightening = lp.duals_normalized(Convention::Tightening);
+        assert_eq!(tightening, shadow);
+    }
+
+    #[test]
+    fn duals_normalized_maximize() {
+        // y = 4 at the right-hand side, x = 0 at its lower bound
+        let lp = model(ObjSense::Maximize, 1.0, 4.0);
+        assert!((lp.obj_val() - 8.0).abs() < 1e-6);
+        let shadow = lp.duals_normalized(Convention::ShadowPrice);
+        let raised = model(ObjSense::Maximize, 1.0, 4.5).obj_val();
+        assert!((shadow.rhs[0] - (raised - lp.obj_val()) / 0.5).abs() < 1e-6);
+        assert_eq!((shadow.lhs[0], shadow.rhs[0]), (0.0, 2.0));
+        // raising the lower bound of x forces it into the solution at a loss of 1
+        assert_eq!((shadow.lower[0], shadow.upper[0]), (-1.0, 0.0));
+
+        let tightening = lp.duals_normalized(Convention::Tightening);
+        assert_eq!((tightening.lhs[0], tightening.rhs[0]), (0.0, 2.0));
+        assert_eq!((tightening.lower[0], tightening.upper[0]), (1.0, 0.0));
+    }
+
+    #[test]
+    fn duals_normalized_upper_bound() {
+        // maximizing x - y pushes x to its upper bound 3 inside the row range
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 3.0);
+        lp.add_col(vec![], -1.0, 0.0, 10.0);
+        lp.add_row(vec![1.0, 1.0], 1.0, 4.0);
+        lp.set_obj_sense(ObjSense::Maximize);
+        let lp = lp.optimize();
+        let shadow = lp.duals_normalized(Convention::ShadowPrice);
+        assert_eq!((shadow.lower[0], shadow.upper[0]), (0.0, 1.0));
+        assert_eq!((shadow.lhs[0], shadow.rhs[0]), (0.0, 0.0));
+
+        let tightening = lp.duals_normalized(Convention::Tightening);
+        assert_eq!((tightening.lower[0], tightening.upper[0]), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::model::LpData;
+use crate::{ColId, Model, ObjSense, RowId};
+
+/// A side of a row or of the bounds of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    /// The left-hand side of a row or the lower bound of a column.
+    Lower,
+    /// The right-hand side of a row or the upper bound of a column.
+    Upper,
+}
+
+/// The constraint of the original model a row of an elastic model stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Origin {
+    /// A row of the original model.
+    Row(RowId),
+    /// The bounds of a column of the original model.
+    Bounds(ColId),
+}
+
+/// Copy of a model in which constraint sides may be violated at a cost.
+///
+/// The columns of the original model come first and are free, their finite bounds become rows
+/// after the original rows. Every elastic side gets a nonnegative slack column, the objective
+/// minimizes the weighted sum of the slacks.
+pub(crate) struct Elastic {
+    /// The elastic model, not yet optimized.
+    pub(crate) model: Model,
+    /// The origin of every row of the elastic model.
+    pub(crate) origins: Vec<Origin>,
+    /// The range of every row of the elastic model.
+    pub(crate) ranges: Vec<(f64, f64)>,
+    /// The row and side of every slack column, the slack columns follow the original columns.
+    pub(crate) slacks: Vec<(RowId, Side)>,
+    /// The number of columns of the original model.
+    pub(crate) num_cols: usize,
+}
+
+impl Elastic {
+    /// Builds the elastic copy of a model.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the original model.
+    /// * `weight` - The weight of the slack of a finite side, `None` keeps the side hard.
+    pub(crate) fn new(data: LpData, mut weight: impl FnMut(Origin, Side) -> Option<f64>) -> Self {
+        let num_cols = data.obj.len();
+        let mut origins: Vec<Origin> = (0..data.rows.len()).map(Origin::Row).collect();
+        let mut ranges = data.row_ranges.clone();
+        let mut rows = data.rows.clone();
+        for (col_id, &(lb, ub)) in data.col_bounds.iter().enumerate() {
+            if data.is_finite(lb) || data.is_finite(ub) {
+                origins.push(Origin::Bounds(col_id));
+                ranges.push((lb, ub));
+                rows.push(vec![(col_id, 1.0)]);
+            }
+        }
+
+        let mut obj = vec![0.0; num_cols];
+        let mut slacks = Vec::new();
+        for (row_id, &(lhs, rhs)) in ranges.iter().enumerate() {
+            for (side, value, coef) in [(Side::Lower, lhs, 1.0), (Side::Upper, rhs, -1.0)] {
+                if !data.is_finite(value) {
+                    continue;
+                }
+                if let Some(weight) = weight(origins[row_id], side) {
+                    rows[row_id].push((num_cols + slacks.len(), coef));
+                    slacks.push((row_id, side));
+                    obj.push(weight);
+                }
+            }
+        }
+
+        let col_bounds: Vec<(f64, f64)> = (0..num_cols)
+            .map(|_| (f64::NEG_INFINITY, f64::INFINITY))
+            .chain(slacks.iter().map(|_| (0.0, f64::INFINITY)))
+            .collect();
+        let mut model = Model::load(&obj, &col_bounds, &ranges, true, rows);
+        model.set_obj_sense(ObjSense::Minimize);
+        Self {
+            model,
+            origins,
+            ranges,
+            slacks,
+            num_cols,
+        }
+    }
+}
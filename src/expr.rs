@@ -0,0 +1,310 @@
+use crate::ColId;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Handle to a variable (column) of a model, returned by `Model::add_var`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Var(pub(crate) ColId);
+
+impl Var {
+    /// Returns the `ColId` of the column of the variable.
+    pub fn col_id(self) -> ColId {
+        self.0
+    }
+}
+
+/// A linear expression `sum(coef * var) + constant`.
+///
+/// Built from `Var`s, `f64`s and `i32`s with the `+`, `-` and `*` operators, e.g. `2.0 * x + y - 3.0`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LinExpr {
+    terms: Vec<(ColId, f64)>,
+    constant: f64,
+}
+
+impl LinExpr {
+    /// Creates the empty expression `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the constant term of the expression.
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Returns the coefficients of the expression by `ColId`, duplicates merged and zeros dropped.
+    pub fn coefs(&self) -> Vec<(ColId, f64)> {
+        let mut terms = self.terms.clone();
+        terms.sort_by_key(|&(col_id, _)| col_id);
+        let mut merged: Vec<(ColId, f64)> = Vec::with_capacity(terms.len());
+        for (col_id, coef) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == col_id => *sum += coef,
+                _ => merged.push((col_id, coef)),
+            }
+        }
+        merged.retain(|&(_, coef)| coef != 0.0);
+        merged
+    }
+
+    /// Returns the coefficients as a dense vector of length `len`.
+    ///
+    /// # Panics
+    /// if the expression contains a column with `ColId >= len`.
+    pub(crate) fn dense_coefs(&self, len: usize) -> Vec<f64> {
+        let mut dense = vec![0.0; len];
+        for &(col_id, coef) in &self.terms {
+            dense[col_id] += coef;
+        }
+        dense
+    }
+
+    /// Returns one more than the largest `ColId` in the expression, 0 if there is none.
+    pub(crate) fn col_bound(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|&(col_id, _)| col_id + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Constraint `self <= rhs`.
+    pub fn le(self, rhs: impl Into<LinExpr>) -> Constraint {
+        Constraint::new(self - rhs.into(), f64::NEG_INFINITY, 0.0)
+    }
+
+    /// Constraint `self >= rhs`.
+    pub fn ge(self, rhs: impl Into<LinExpr>) -> Constraint {
+        Constraint::new(self - rhs.into(), 0.0, f64::INFINITY)
+    }
+
+    /// Constraint `self == rhs`.
+    pub fn eq(self, rhs: impl Into<LinExpr>) -> Constraint {
+        Constraint::new(self - rhs.into(), 0.0, 0.0)
+    }
+
+    /// Constraint `lhs <= self <= rhs`.
+    pub fn between(self, lhs: f64, rhs: f64) -> Constraint {
+        Constraint::new(self, lhs, rhs)
+    }
+}
+
+/// A linear constraint `lhs <= expr <= rhs`, added to a model with `Model::add_constr`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    expr: LinExpr,
+    lhs: f64,
+    rhs: f64,
+}
+
+impl Constraint {
+    /// Creates the constraint, folding the constant of `expr` into the sides.
+    fn new(mut expr: LinExpr, lhs: f64, rhs: f64) -> Self {
+        let constant = std::mem::take(&mut expr.constant);
+        Self {
+            expr,
+            lhs: lhs - constant,
+            rhs: rhs - constant,
+        }
+    }
+
+    /// Returns the expression of the constraint, its constant is always zero.
+    pub fn expr(&self) -> &LinExpr {
+        &self.expr
+    }
+
+    /// Returns the left-hand side of the constraint.
+    pub fn lhs(&self) -> f64 {
+        self.lhs
+    }
+
+    /// Returns the right-hand side of the constraint.
+    pub fn rhs(&self) -> f64 {
+        self.rhs
+    }
+}
+
+impl From<Var> for LinExpr {
+    fn from(var: Var) -> Self {
+        Self {
+            terms: vec![(var.0, 1.0)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl From<f64> for LinExpr {
+    fn from(constant: f64) -> Self {
+        Self {
+            terms: Vec::new(),
+            constant,
+        }
+    }
+}
+
+impl From<i32> for LinExpr {
+    fn from(constant: i32) -> Self {
+        Self::from(f64::from(constant))
+    }
+}
+
+impl<T: Into<LinExpr>> AddAssign<T> for LinExpr {
+    fn add_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        self.terms.extend(rhs.terms);
+        self.constant += rhs.constant;
+    }
+}
+
+impl<T: Into<LinExpr>> SubAssign<T> for LinExpr {
+    fn sub_assign(&mut self, rhs: T) {
+        *self += -rhs.into();
+    }
+}
+
+impl MulAssign<f64> for LinExpr {
+    fn mul_assign(&mut self, rhs: f64) {
+        for (_, coef) in &mut self.terms {
+            *coef *= rhs;
+        }
+        self.constant *= rhs;
+    }
+}
+
+impl Neg for LinExpr {
+    type Output = LinExpr;
+
+    fn neg(mut self) -> LinExpr {
+        self *= -1.0;
+        self
+    }
+}
+
+impl Neg for Var {
+    type Output = LinExpr;
+
+    fn neg(self) -> LinExpr {
+        -LinExpr::from(self)
+    }
+}
+
+macro_rules! impl_expr_ops {
+    (@scalar $lhs:ty, $($scalar:ty),*) => {
+        $(
+            impl Mul<$scalar> for $lhs {
+                type Output = LinExpr;
+
+                fn mul(self, rhs: $scalar) -> LinExpr {
+                    let mut expr = LinExpr::from(self);
+                    expr *= f64::from(rhs);
+                    expr
+                }
+            }
+
+            impl Mul<$lhs> for $scalar {
+                type Output = LinExpr;
+
+                fn mul(self, rhs: $lhs) -> LinExpr {
+                    rhs * self
+                }
+            }
+
+            impl Add<$lhs> for $scalar {
+                type Output = LinExpr;
+
+                fn add(self, rhs: $lhs) -> LinExpr {
+                    rhs + self
+                }
+            }
+
+            impl Sub<$lhs> for $scalar {
+                type Output = LinExpr;
+
+                fn sub(self, rhs: $lhs) -> LinExpr {
+                    -rhs + self
+                }
+            }
+        )*
+    };
+    ($($lhs:ty),*) => {
+        $(
+            impl<T: Into<LinExpr>> Add<T> for $lhs {
+                type Output = LinExpr;
+
+                fn add(self, rhs: T) -> LinExpr {
+                    let mut expr = LinExpr::from(self);
+                    expr += rhs;
+                    expr
+                }
+            }
+
+            impl<T: Into<LinExpr>> Sub<T> for $lhs {
+                type Output = LinExpr;
+
+                fn sub(self, rhs: T) -> LinExpr {
+                    let mut expr = LinExpr::from(self);
+                    expr -= rhs;
+                    expr
+                }
+            }
+
+            impl_expr_ops!(@scalar $lhs, f64, i32);
+        )*
+    };
+}
+
+impl_expr_ops!(Var, LinExpr);
+
+impl<T: Into<LinExpr>> std::iter::Sum<T> for LinExpr {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        let mut expr = LinExpr::new();
+        for term in iter {
+            expr += term;
+        }
+        expr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let (x, y) = (Var(0), Var(2));
+        let expr = 2.0 * x + y * 3.0 - x + 1.0 - (y - 4.0) * 0.5;
+        assert_eq!(expr.coefs(), vec![(0, 1.0), (2, 2.5)]);
+        assert_eq!(expr.constant(), 3.0);
+        assert_eq!(expr.dense_coefs(expr.col_bound()), vec![1.0, 0.0, 2.5]);
+
+        let expr = x - x;
+        assert_eq!(expr.coefs(), vec![]);
+        assert_eq!(expr.col_bound(), 1);
+
+        let expr: LinExpr = [x, y, x].into_iter().sum();
+        assert_eq!(expr.coefs(), vec![(0, 2.0), (2, 1.0)]);
+        assert_eq!((5.0 - x).coefs(), vec![(0, -1.0)]);
+
+        let expr = 3 * x + y * 2 - 1;
+        assert_eq!(expr.coefs(), vec![(0, 3.0), (2, 2.0)]);
+        assert_eq!(expr.constant(), -1.0);
+    }
+
+    #[test]
+    fn constraints_fold_constant() {
+        let (x, y) = (Var(0), Var(1));
+        let c = (x + y + 1.0).le(5.0);
+        assert_eq!((c.lhs(), c.rhs()), (f64::NEG_INFINITY, 4.0));
+        assert_eq!(c.expr().constant(), 0.0);
+
+        let c = (x + 2.0).ge(y);
+        assert_eq!(c.expr().coefs(), vec![(0, 1.0), (1, -1.0)]);
+        assert_eq!((c.lhs(), c.rhs()), (-2.0, f64::INFINITY));
+
+        let c = LinExpr::from(x).eq(3.0);
+        assert_eq!((c.lhs(), c.rhs()), (3.0, 3.0));
+
+        let c = (x - 1.0).between(0.0, 2.0);
+        assert_eq!((c.lhs(), c.rhs()), (1.0, 3.0));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::elastic::{Elastic, Origin, Side};
+use crate::{ColId, Model, RowId, SolvedModel, Status};
+use std::fmt;
+
+/// Tolerance for the infeasibility of the phase one problem and for nonzero duals.
+const IIS_TOL: f64 = 1e-9;
+
+/// An irreducible infeasible subsystem (IIS) of a model, see `SolvedModel::compute_iis`.
+///
+/// The listed row sides and bounds can not be satisfied together, but every proper subset of them can.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Iis {
+    /// The rows in the subsystem with the side that takes part.
+    pub rows: Vec<(RowId, Side)>,
+    /// The column bounds in the subsystem.
+    pub col_bounds: Vec<(ColId, Side)>,
+}
+
+/// Error returned when `SolvedModel::compute_iis` finds no IIS.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IisError {
+    /// The model is feasible, so it has no infeasible subsystem.
+    Feasible,
+    /// An auxiliary LP ended with a status other than optimal or infeasible, e.g. because of a
+    /// time or iteration limit.
+    NotSolved(Status),
+}
+
+impl fmt::Display for IisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IisError::Feasible => write!(f, "the model is feasible"),
+            IisError::NotSolved(status) => {
+                write!(f, "an auxiliary LP was not solved: {:?}", status)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IisError {}
+
+impl SolvedModel {
+    /// Computes an irreducible infeasible subsystem of the model.
+    ///
+    /// The C interface of SoPlex does not expose Farkas rays, so an elastic copy of the model that
+    /// minimizes the total violation of all row sides and bounds is solved first. Its dual solution
+    /// is a Farkas ray of the model and its support an infeasible subsystem. This subsystem is
+    /// then reduced by deletion filtering: every side is dropped in turn and kept out if the rest stays
+    /// infeasible, each test warm started from the previous one.
+    ///
+    /// A side only counts as redundant if the rest is solved to optimality, so a limit hit in one
+    /// of these LPs can not make the result too small.
+    ///
+    /// # Returns
+    /// The IIS. This is a `Result` rather than a plain `Iis` because a feasible model has none and
+    /// the auxiliary LPs may stop early, which must not be confused with each other.
+    pub fn compute_iis(&self) -> Result<Iis, IisError> {
+        let data = self.lp_data();
+        let elastic = Elastic::new(data.clone(), |_, _| Some(1.0));
+        let solved = elastic.model.optimize();
+        match solved.status() {
+            Status::Optimal if solved.obj_val() <= IIS_TOL => return Err(IisError::Feasible),
+            Status::Optimal => {}
+            status => return Err(IisError::NotSolved(status)),
+        }
+
+        // support of the Farkas ray
+        let mut active: Vec<(bool, bool)> = solved
+            .dual_solution()
+            .iter()
+            .map(|&dual| (dual > IIS_TOL, dual < -IIS_TOL))
+            .collect();
+
+        // the support is filtered on a copy without slacks and with a zero objective that keeps only
+        // the active sides
+        let ranges = elastic.ranges;
+        let range = |(lhs, rhs): (f64, f64), (lower, upper): (bool, bool)| {
+            (
+                if lower { lhs } else { f64::NEG_INFINITY },
+                if upper { rhs } else { f64::INFINITY },
+            )
+        };
+        let restricted = |active: &[(bool, bool)]| {
+            let mut model = Elastic::new(data.clone(), |_, _| None).model;
+            for (row_id, &sides) in active.iter().enumerate() {
+                let (lhs, rhs) = range(ranges[row_id], sides);
+                model.change_row_range(row_id, lhs, rhs);
+            }
+            model
+        };
+        let mut model = restricted(&active);
+
+        // deletion filter
+        let candidates: Vec<(RowId, Side)> = active
+            .iter()
+            .enumerate()
+            .flat_map(|(row_id, &(lower, upper))| {
+                let lower = lower.then_some((row_id, Side::Lower));
+                let upper = upper.then_some((row_id, Side::Upper));
+                lower.into_iter().chain(upper)
+            })
+            .collect();
+        let mut iis = Iis::default();
+        for (row_id, side) in candidates {
+            let sides = active[row_id];
+            match side {
+                Side::Lower => active[row_id].0 = false,
+                Side::Upper => active[row_id].1 = false,
+            }
+            let (lhs, rhs) = range(ranges[row_id], active[row_id]);
+            model.change_row_range(row_id, lhs, rhs);
+            let mut solved = model.optimize();
+            if !matches!(solved.status(), Status::Optimal | Status::Infeasible) {
+                // SoPlex aborts some warm started solves with an internal error, such a test is
+                // repeated from scratch
+                solved = restricted(&active).optimize();
+            }
+            let status = solved.status();
+            model = Model::from(solved);
+            match status {
+                Status::Infeasible => continue,
+                Status::Optimal => {}
+                status => return Err(IisError::NotSolved(status)),
+            }
+
+            active[row_id] = sides;
+            let (lhs, rhs) = range(ranges[row_id], sides);
+            model.change_row_range(row_id, lhs, rhs);
+            match elastic.origins[row_id] {
+                Origin::Row(row_id) => iis.rows.push((row_id, side)),
+                Origin::Bounds(col_id) => iis.col_bounds.push((col_id, side)),
+            }
+        }
+        Ok(iis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iis() {
+        // x + y >= 5 conflicts with x, y <= 1, the other rows are not involved
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_col(vec![], 1.0, 0.0, 10.0);
+        lp.add_row(vec![0.0, 0.0, 1.0], 0.0, 3.0);
+        lp.add_row(vec![1.0, 1.0], 5.0, f64::INFINITY);
+        lp.add_row(vec![1.0, 0.0, 1.0], 1.0, f64::INFINITY);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Infeasible);
+
+        let iis = lp.compute_iis().unwrap();
+        assert_eq!(iis.rows, vec![(1, Side::Lower)]);
+        assert_eq!(iis.col_bounds, vec![(0, Side::Upper), (1, Side::Upper)]);
+    }
+
+    #[test]
+    fn iis_feasible() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0], 0.5, 2.0);
+        assert_eq!(lp.optimize().compute_iis(), Err(IisError::Feasible));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Model, ObjSense, RowId, SolvedModel, Status};
+
+/// One objective of a lexicographic optimization, see `Model::optimize_lexicographic`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    /// The objective value of every column.
+    pub coefs: Vec<f64>,
+    /// The objective sense.
+    pub sense: ObjSense,
+    /// Relative degradation of the optimal value allowed in later stages.
+    pub rel_tol: f64,
+    /// Absolute degradation of the optimal value allowed in later stages.
+    pub abs_tol: f64,
+}
+
+impl Objective {
+    /// Creates an objective that has to stay optimal in later stages.
+    ///
+    /// # Arguments
+    /// * `coefs` - The objective value of every column.
+    /// * `sense` - The objective sense.
+    pub fn new(coefs: Vec<f64>, sense: ObjSense) -> Self {
+        Self {
+            coefs,
+            sense,
+            rel_tol: 0.0,
+            abs_tol: 0.0,
+        }
+    }
+}
+
+/// Result of one stage of a lexicographic optimization.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LexStage {
+    /// Status of the solve of the stage.
+    pub status: Status,
+    /// Optimal value of the objective of the stage, without the objective offset.
+    pub obj_val: f64,
+    /// The row that keeps the objective near its optimal value in later stages, `None` for the last stage.
+    pub constraint: Option<RowId>,
+}
+
+/// Result of `Model::optimize_lexicographic`.
+#[non_exhaustive]
+pub struct LexicographicResult {
+    /// The results of the stages that were solved, in order.
+    pub stages: Vec<LexStage>,
+    /// The model solved for the last objective, including the rows added for the earlier ones.
+    pub model: SolvedModel,
+}
+
+impl Model {
+    /// Optimizes the objectives one after another, each one is kept within its tolerance of
+    /// its optimal value while the later ones are optimized.
+    ///
+    /// After a stage is solved, the row `coefs^T x <= z + tol` (`>= z - tol` when maximizing) with
+    /// `tol = max(abs_tol, rel_tol * |z|)` is added for its optimal value `z`, and the next stage
+    /// is warm started from the current basis. The optimization stops at the first stage that
+    /// does not end optimal.
+    ///
+    /// # Arguments
+    /// * `objectives` - The objectives in order of priority.
+    ///
+    /// # Panics
+    /// if `objectives` is empty or an objective does not have one entry per column.
+    pub fn optimize_lexicographic(self, objectives: &[Objective]) -> LexicographicResult {
+        let num_cols = self.num_cols();
+        assert!(
+            objectives.iter().all(|obj| obj.coefs.len() == num_cols),
+            "every objective must have one entry per column"
+        );
+        let (last, earlier) = objectives
+            .split_last()
+            .expect("objectives must not be empty");
+
+        let mut model = self;
+        let mut stages = Vec::with_capacity(objectives.len());
+        for objective in earlier {
+            let (solved, status, obj_val) = model.optimize_stage(objective);
+            if status != Status::Optimal {
+                stages.push(LexStage {
+                    status,
+                    obj_val,
+                    constraint: None,
+                });
+                return LexicographicResult {
+                    stages,
+                    model: solved,
+                };
+            }
+
+            model = Model::from(solved);
+            let tol = objective.abs_tol.max(objective.rel_tol * obj_val.abs());
+            let (lhs, rhs) = match objective.sense {
+                ObjSense::Minimize => (f64::NEG_INFINITY, obj_val + tol),
+                ObjSense::Maximize => (obj_val - tol, f64::INFINITY),
+            };
+            let row_id = model.add_row(objective.coefs.clone(), lhs, rhs);
+            stages.push(LexStage {
+                status,
+                obj_val,
+                constraint: Some(row_id),
+            });
+        }
+
+        let (solved, status, obj_val) = model.optimize_stage(last);
+        stages.push(LexStage {
+            status,
+            obj_val,
+            constraint: None,
+        });
+        LexicographicResult {
+            stages,
+            model: solved,
+        }
+    }
+
+    /// Optimizes the objective of a stage, returns the status and the value of the objective.
+    fn optimize_stage(mut self, objective: &Objective) -> (SolvedModel, Status, f64) {
+        self.set_obj_vals(&objective.coefs);
+        self.set_obj_sense(objective.sense);
+        let solved = self.optimize();
+        let status = solved.status();
+        let obj_val = solved
+            .primal_solution()
+            .iter()
+            .zip(&objective.coefs)
+            .map(|(x, c)| x * c)
+            .sum();
+        (solved, status, obj_val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> Model {
+        // x + y >= 4, 0 <= x, y <= 10
+        let mut lp = Model::new();
+        lp.add_col(vec![], 0.0, 0.0, 10.0);
+        lp.add_col(vec![], 0.0, 0.0, 10.0);
+        lp.add_row(vec![1.0, 1.0], 4.0, f64::INFINITY);
+        lp
+    }
+
+    #[test]
+    fn lexicographic() {
+        let cost = Objective::new(vec![1.0, 1.0], ObjSense::Minimize);
+        let share = Objective::new(vec![1.0, 0.0], ObjSense::Maximize);
+        let result = model().optimize_lexicographic(&[cost.clone(), share.clone()]);
+        assert_eq!(result.stages.len(), 2);
+        assert!((result.stages[0].obj_val - 4.0).abs() < 1e-6);
+        assert_eq!(result.stages[0].constraint, Some(1));
+        assert!((result.stages[1].obj_val - 4.0).abs() < 1e-6);
+        assert_eq!(result.model.status(), Status::Optimal);
+        assert_eq!(result.model.num_rows(), 2);
+
+        // allowing the cost to degrade by 1
+        let cost = Objective {
+            abs_tol: 1.0,
+            ..cost
+        };
+        let result = model().optimize_lexicographic(&[cost, share]);
+        let primal = result.model.primal_solution();
+        assert!((primal[0] - 5.0).abs() < 1e-6 && primal[1].abs() < 1e-6);
+    }
+
+    #[test]
+    fn lexicographic_stops_early() {
+        let unbounded = Objective::new(vec![1.0, 0.0], ObjSense::Maximize);
+        let mut lp = model();
+        lp.change_col_bounds(0, 0.0, f64::INFINITY);
+        let result = lp.optimize_lexicographic(&[
+            unbounded,
+            Objective::new(vec![0.0, 1.0], ObjSense::Minimize),
+        ]);
+        assert_eq!(result.stages.len(), 1);
+        assert_eq!(result.stages[0].status, Status::Unbounded);
+    }
+}
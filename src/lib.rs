@@ -42,7 +42,21 @@
 //! assert!((lp.obj_val() - 5.0).abs() < 1e-6);
 //! ```
 //!
-//! TODO: Setting parameters
+//! # Parameters
+//! Parameters are read and written through `Model::param` and `Model::set_param`, values outside of the
+//! range accepted by SoPlex are rejected. `Model::parameters` returns a snapshot of all parameters.
+//! ```rust
+//! use soplex_rs::*;
+//!
+//! let mut lp = Model::new();
+//! lp.set_param(RealParam::FeasTol, 1e-9).unwrap();
+//! lp.set_param(IntParam::IterLimit, 1000).unwrap();
+//! assert!(lp.set_param(RealParam::FeasTol, -1.0).is_err());
+//! assert_eq!(lp.param(RealParam::FeasTol), 1e-9);
+//!
+//! lp.set_pricer_type(Pricer::Devex);
+//! assert_eq!(lp.parameters().pricer_type(), Pricer::Devex);
+//! ```
 
 #![deny(missing_docs)]
 
@@ -56,11 +70,57 @@ pub mod ffi {
 }
 
 mod basis_status;
+mod benders;
+mod colgen;
+mod cutloop;
+#[cfg(feature = "ndarray")]
+mod dense;
+mod duals;
+mod elastic;
+mod expr;
+mod iis;
+mod lexicographic;
+mod linprog;
+mod macros;
+#[cfg(feature = "mip")]
+mod mip;
 mod model;
 mod param;
+mod parameters;
+mod parametric;
+mod relaxation;
+mod removal;
+mod settings;
+mod solution;
 mod soplex_ptr;
+#[cfg(any(feature = "sprs", feature = "nalgebra-sparse"))]
+mod sparse;
+mod statistics;
+mod structure;
 pub use basis_status::*;
+pub use benders::{Benders, BendersIteration, BendersResult, BendersStatus, Scenario};
+pub use colgen::{
+    ColGenIteration, ColGenResult, ColGenStatus, ColumnGeneration, NewColumn, PricingOracle,
+};
+pub use cutloop::{Cut, CutLoop, CutLoopResult, CutLoopStatus, CutRound, Separator};
+pub use duals::{Convention, NormalizedDuals};
+pub use elastic::Side;
+pub use expr::{Constraint, LinExpr, Var};
+pub use iis::{Iis, IisError};
+pub use lexicographic::{LexStage, LexicographicResult, Objective};
+pub use linprog::{linprog, LinprogMarginals, LinprogResult};
+pub use macros::NamedModel;
+#[cfg(feature = "mip")]
+pub use mip::{BranchAndBound, MipResult, MipStatus, NodeSelection};
+pub use statistics::*;
+pub use structure::{Block, Structure};
 
 pub use param::*;
+pub use parameters::{Param, ParamError, Parameters};
+pub use parametric::ParametricInterval;
+pub use relaxation::{Relaxation, RelaxationWeights};
+pub use removal::{ColInfo, RowInfo};
+pub use settings::SettingsError;
+pub use solution::Solution;
 
 pub use model::*;
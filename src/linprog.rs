@@ -0,0 +1,214 @@
+use crate::{Model, ObjSense, Status};
+
+/// Result of [`linprog`], modeled after the result of scipy's `linprog`.
+///
+/// The solution vectors are only filled if `success` is true, otherwise they are empty and `fun` is NaN.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LinprogResult {
+    /// The values of the variables.
+    pub x: Vec<f64>,
+    /// The optimal objective value `c^T x`.
+    pub fun: f64,
+    /// The slacks `b_ub - A_ub x` of the inequality constraints.
+    pub slack: Vec<f64>,
+    /// The residuals `b_eq - A_eq x` of the equality constraints.
+    pub con: Vec<f64>,
+    /// The sensitivities of `fun` to the right-hand sides and bounds.
+    pub marginals: LinprogMarginals,
+    /// The status of the solve.
+    pub status: Status,
+    /// Whether an optimal solution was found.
+    pub success: bool,
+    /// Description of the status.
+    pub message: &'static str,
+}
+
+/// Marginals (dual values) of a [`LinprogResult`], the partial derivatives of the optimal
+/// objective value with respect to the right-hand sides and bounds.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct LinprogMarginals {
+    /// Marginals of the inequality constraints, nonpositive.
+    pub ineqlin: Vec<f64>,
+    /// Marginals of the equality constraints.
+    pub eqlin: Vec<f64>,
+    /// Marginals of the lower bounds, nonnegative.
+    pub lower: Vec<f64>,
+    /// Marginals of the upper bounds, nonpositive.
+    pub upper: Vec<f64>,
+}
+
+/// Solves `min c^T x` subject to `A_ub x <= b_ub`, `A_eq x == b_eq` and `lb <= x <= ub`, like
+/// scipy's `linprog`.
+///
+/// # Arguments
+/// * `c` - The objective value of every variable.
+/// * `a_ub` - The rows of the inequality constraint matrix, each of length `c.len()`.
+/// * `b_ub` - The right-hand side of every inequality constraint.
+/// * `a_eq` - The rows of the equality constraint matrix, each of length `c.len()`.
+/// * `b_eq` - The right-hand side of every equality constraint.
+/// * `bounds` - The lower and upper bound of every variable, if empty every variable is nonnegative.
+///
+/// # Panics
+/// if the dimensions of the arguments do not match.
+///
+/// # Example
+/// ```
+/// use soplex_rs::*;
+///
+/// // min -x - 2y  s.t.  x + y <= 4, x - y == 1, x, y >= 0
+/// let res = linprog(&[-1.0, -2.0], &[vec![1.0, 1.0]], &[4.0], &[vec![1.0, -1.0]], &[1.0], &[]);
+/// assert!(res.success);
+/// assert!((res.fun + 5.5).abs() < 1e-6);
+/// assert!((res.x[0] - 2.5).abs() < 1e-6);
+/// ```
+pub fn linprog(
+    c: &[f64],
+    a_ub: &[Vec<f64>],
+    b_ub: &[f64],
+    a_eq: &[Vec<f64>],
+    b_eq: &[f64],
+    bounds: &[(f64, f64)],
+) -> LinprogResult {
+    let num_cols = c.len();
+    assert_eq!(
+        a_ub.len(),
+        b_ub.len(),
+        "a_ub and b_ub must have the same number of rows"
+    );
+    assert_eq!(
+        a_eq.len(),
+        b_eq.len(),
+        "a_eq and b_eq must have the same number of rows"
+    );
+    assert!(
+        a_ub.iter().chain(a_eq).all(|row| row.len() == num_cols),
+        "every row of a_ub and a_eq must have one entry per variable"
+    );
+    let bounds = if bounds.is_empty() {
+        vec![(0.0, f64::INFINITY); num_cols]
+    } else {
+        assert_eq!(
+            bounds.len(),
+            num_cols,
+            "bounds must have one entry per variable"
+        );
+        bounds.to_vec()
+    };
+
+    let row_ranges: Vec<_> = b_ub
+        .iter()
+        .map(|&b| (f64::NEG_INFINITY, b))
+        .chain(b_eq.iter().map(|&b| (b, b)))
+        .collect();
+    let rows = a_ub.iter().chain(a_eq).map(|row| {
+        row.iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, value)| value != 0.0)
+    });
+    let mut lp = Model::load(c, &bounds, &row_ranges, true, rows);
+    lp.set_obj_sense(ObjSense::Minimize);
+    let lp = lp.optimize();
+
+    let status = lp.status();
+    let message = message(&status);
+    if status != Status::Optimal {
+        return LinprogResult {
+            x: Vec::new(),
+            fun: f64::NAN,
+            slack: Vec::new(),
+            con: Vec::new(),
+            marginals: LinprogMarginals::default(),
+            status,
+            success: false,
+            message,
+        };
+    }
+
+    let x = lp.primal_solution();
+    let residuals = |rows: &[Vec<f64>], rhs: &[f64]| -> Vec<f64> {
+        rows.iter()
+            .zip(rhs)
+            .map(|(row, b)| b - row.iter().zip(&x).map(|(a, x)| a * x).sum::<f64>())
+            .collect()
+    };
+    let slack = residuals(a_ub, b_ub);
+    let con = residuals(a_eq, b_eq);
+    let mut dual = lp.dual_solution();
+    let eqlin = dual.split_off(b_ub.len());
+    let reduced_costs = lp.reduced_costs();
+    let marginals = LinprogMarginals {
+        ineqlin: dual,
+        eqlin,
+        lower: reduced_costs.iter().map(|&rc| rc.max(0.0)).collect(),
+        upper: reduced_costs.iter().map(|&rc| rc.min(0.0)).collect(),
+    };
+
+    LinprogResult {
+        fun: lp.obj_val(),
+        x,
+        slack,
+        con,
+        marginals,
+        status,
+        success: true,
+        message,
+    }
+}
+
+fn message(status: &Status) -> &'static str {
+    match status {
+        Status::Optimal => "Optimization terminated successfully.",
+        Status::Infeasible => "The problem is infeasible.",
+        Status::Unbounded => "The problem is unbounded.",
+        Status::InfOrUnbd => "The problem is infeasible or unbounded.",
+        Status::AbortIter => "Iteration limit reached.",
+        Status::AbortTime => "Time limit reached.",
+        Status::OptimalUnscaledViolations => {
+            "The solution of the scaled problem violates the unscaled constraints."
+        }
+        _ => "Numerical difficulties encountered.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linprog_optimal() {
+        // min -x - 2y  s.t.  x + y <= 4, x + 3y <= 6, x - y == 0, 0 <= x <= 10, -1 <= y <= 10
+        let res = linprog(
+            &[-1.0, -2.0],
+            &[vec![1.0, 1.0], vec![1.0, 3.0]],
+            &[4.0, 6.0],
+            &[vec![1.0, -1.0]],
+            &[0.0],
+            &[(0.0, 10.0), (-1.0, 10.0)],
+        );
+        assert!(res.success);
+        assert_eq!(res.status, Status::Optimal);
+        assert!((res.fun + 4.5).abs() < 1e-6);
+        assert!((res.x[0] - 1.5).abs() < 1e-6 && (res.x[1] - 1.5).abs() < 1e-6);
+        assert!((res.slack[0] - 1.0).abs() < 1e-6 && res.slack[1].abs() < 1e-6);
+        assert!(res.con[0].abs() < 1e-6);
+
+        // the second row is binding: fun = -0.75 * b_ub[1]
+        assert!(res.marginals.ineqlin[0].abs() < 1e-6);
+        assert!((res.marginals.ineqlin[1] + 0.75).abs() < 1e-6);
+        assert_eq!(res.marginals.eqlin.len(), 1);
+        assert!(res.marginals.lower.iter().all(|&m| m.abs() < 1e-6));
+        assert!(res.marginals.upper.iter().all(|&m| m.abs() < 1e-6));
+    }
+
+    #[test]
+    fn linprog_infeasible() {
+        let res = linprog(&[1.0], &[vec![1.0]], &[-1.0], &[], &[], &[]);
+        assert!(!res.success);
+        assert_eq!(res.status, Status::Infeasible);
+        assert_eq!(res.message, "The problem is infeasible.");
+        assert!(res.x.is_empty() && res.fun.is_nan());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{Model, RowId, Var};
+use std::collections::HashMap;
+
+/// A model built by the [`lp!`](crate::lp) macro, together with its variables and constraints by name.
+pub struct NamedModel {
+    /// The model.
+    pub model: Model,
+    /// The variables of the model by name.
+    pub vars: HashMap<&'static str, Var>,
+    /// The constraints of the model by name.
+    pub constrs: HashMap<&'static str, RowId>,
+}
+
+/// Builds a [`Model`](crate::Model) from an inline LP description.
+///
+/// The description starts with the objective (`minimize` or `maximize` followed by a linear
+/// expression), optionally followed by `subject to` and a list of statements, each ending in `;`:
+/// * `name: expr >= expr;`, `name: expr <= expr;` or `name: expr == expr;` adds a constraint,
+/// * `name: lhs <= expr <= rhs;` adds a ranged constraint with constant sides,
+/// * `x in [lb, ub];`, `x >= lb;`, `x <= ub;` or `x free;` declares the variable `x` with its bounds.
+///
+/// Every variable used in the objective or a constraint must be declared exactly once, the
+/// declarations may appear anywhere in the list. A variable with both bounds is declared with
+/// `x in [lb, ub];`, declaring it a second time (as in `x >= 0; x <= 10;`) is a compile error.
+/// Expressions are regular Rust expressions over the declared variables and may use `f64` and
+/// `i32` constants as well as other variables in scope.
+///
+/// The macro recurses once per statement (and once per eight tokens of statements longer than
+/// that), very large models may need a higher `#![recursion_limit]` in the calling crate.
+///
+/// Returns a [`NamedModel`](crate::NamedModel).
+///
+/// # Example
+/// ```
+/// use soplex_rs::*;
+///
+/// let lp = lp! {
+///     minimize 3*x + 2*y;
+///     subject to
+///     c1: x + y >= 4;
+///     c2: 1 <= x - y <= 2;
+///     x in [0, 10];
+///     y >= 0;
+/// };
+/// let solved = lp.model.optimize();
+/// assert_eq!(solved.status(), Status::Optimal);
+/// assert!((solved.obj_val() - 10.5).abs() < 1e-6);
+/// assert!((solved.primal_solution()[lp.vars["x"].col_id()] - 2.5).abs() < 1e-6);
+/// assert_eq!(lp.constrs["c2"], 1);
+/// ```
+///
+/// ```compile_fail
+/// use soplex_rs::*;
+///
+/// let lp = lp! {
+///     minimize x;
+///     x >= 0;
+///     x <= 10;
+/// };
+/// ```
+#[macro_export]
+macro_rules! lp {
+    (minimize $($rest:tt)*) => {
+        $crate::lp!(@split Minimize [] [] $($rest)*)
+    };
+    (maximize $($rest:tt)*) => {
+        $crate::lp!(@split Maximize [] [] $($rest)*)
+    };
+
+    // Splits the input into statements, taking up to eight tokens per step so that the
+    // recursion depth grows with the number of statements rather than the number of tokens.
+    (@split $sense:ident [$($stmts:tt)*] []) => {
+        $crate::lp!(@build $sense $($stmts)*)
+    };
+    (@split $sense:ident [$obj:tt] [] subject to $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$obj] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)*]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2 $t3]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2 $t3 $t4]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2 $t3 $t4 $t5]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2 $t3 $t4 $t5 $t6]] [] $($rest)*)
+    };
+    (@split $sense:ident [$($stmts:tt)*] [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ; $($rest:tt)*) => {
+        $crate::lp!(@split $sense [$($stmts)* [$($cur)* $t1 $t2 $t3 $t4 $t5 $t6 $t7]] []
+            $($rest)*)
+    };
+    (@split $sense:ident $stmts:tt [$($cur:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($rest:tt)*) => {
+        $crate::lp!(@split $sense $stmts [$($cur)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8] $($rest)*)
+    };
+    (@split $sense:ident $stmts:tt [$($cur:tt)*] $($rest:tt)+) => {
+        compile_error!(concat!("expected `;` after `", stringify!($($cur)* $($rest)*), "`"))
+    };
+
+    // Each statement is expanded on its own, once for the variables and once for the
+    // constraints, so parsing a constraint only recurses over its own tokens.
+    (@build $sense:ident [$($obj:tt)*] $($stmt:tt)*) => {{
+        let mut model = $crate::Model::new();
+        let mut vars = ::std::collections::HashMap::new();
+        $(
+            $crate::lp!(@var model vars $stmt);
+        )*
+        model.set_objective(&$crate::LinExpr::from($($obj)*), $crate::ObjSense::$sense);
+        #[allow(unused_mut)]
+        let mut constrs = ::std::collections::HashMap::new();
+        $(
+            $crate::lp!(@constr model constrs $stmt);
+        )*
+        $crate::NamedModel {
+            model,
+            vars,
+            constrs,
+        }
+    }};
+
+    // variable declarations, the item in the type namespace rejects a second declaration
+    (@var $model:ident $vars:ident [$var:ident in [$lb:expr, $ub:expr]]) => {
+        $crate::lp!(@add_var $model $vars $var, $lb, $ub)
+    };
+    (@var $model:ident $vars:ident [$var:ident free]) => {
+        $crate::lp!(@add_var $model $vars $var, f64::NEG_INFINITY, f64::INFINITY)
+    };
+    (@var $model:ident $vars:ident [$var:ident >= $lb:expr]) => {
+        $crate::lp!(@add_var $model $vars $var, $lb, f64::INFINITY)
+    };
+    (@var $model:ident $vars:ident [$var:ident <= $ub:expr]) => {
+        $crate::lp!(@add_var $model $vars $var, f64::NEG_INFINITY, $ub)
+    };
+    (@var $model:ident $vars:ident [$name:ident : $($constr:tt)*]) => {};
+    (@var $model:ident $vars:ident [$($stmt:tt)*]) => {
+        compile_error!(concat!("invalid statement `", stringify!($($stmt)*), "`"))
+    };
+    (@add_var $model:ident $vars:ident $var:ident, $lb:expr, $ub:expr) => {
+        #[allow(dead_code, non_camel_case_types)]
+        struct $var {}
+        let $var = $model.add_var(0.0, ($lb) as f64, ($ub) as f64);
+        $vars.insert(stringify!($var), $var);
+    };
+
+    // constraints, left-hand expression up to the comparison operator
+    (@constr $model:ident $constrs:ident [$name:ident : $($constr:tt)*]) => {
+        $crate::lp!(@lhs $model $constrs $name [] $($constr)*)
+    };
+    (@constr $model:ident $constrs:ident $stmt:tt) => {};
+    (@lhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] >= $($rhs:tt)+) => {
+        $crate::lp!(@add_constr $model $constrs $name
+            $crate::LinExpr::from($($lhs)*).ge($($rhs)*))
+    };
+    (@lhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] == $($rhs:tt)+) => {
+        $crate::lp!(@add_constr $model $constrs $name
+            $crate::LinExpr::from($($lhs)*).eq($($rhs)*))
+    };
+    (@lhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] <= $($rest:tt)+) => {
+        $crate::lp!(@rhs $model $constrs $name [$($lhs)*] [] $($rest)*)
+    };
+    (@lhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::lp!(@lhs $model $constrs $name [$($lhs)* $t] $($rest)*)
+    };
+
+    // right-hand expression of a `<=` constraint, a second `<=` makes it a range
+    (@rhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] [$($rhs:tt)*]) => {
+        $crate::lp!(@add_constr $model $constrs $name
+            $crate::LinExpr::from($($lhs)*).le($($rhs)*))
+    };
+    (@rhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] [$($mid:tt)*]
+        <= $($rhs:tt)+) => {
+        $crate::lp!(@add_constr $model $constrs $name
+            $crate::LinExpr::from($($mid)*).between(($($lhs)*) as f64, ($($rhs)*) as f64))
+    };
+    (@rhs $model:ident $constrs:ident $name:ident [$($lhs:tt)*] [$($rhs:tt)*]
+        $t:tt $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $constrs $name [$($lhs)*] [$($rhs)* $t] $($rest)*)
+    };
+    (@add_constr $model:ident $constrs:ident $name:ident $constr:expr) => {
+        $constrs.insert(stringify!($name), $model.add_constr($constr));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ObjSense, Status};
+
+    #[test]
+    fn lp_macro() {
+        let lp = crate::lp! {
+            maximize x + 2.5 * y - z;
+            subject to
+            x in [0, 4];
+            cap: x + y + z <= 10;
+            y <= 3;
+            w free;
+            balance: x - z == 2 * y - 1;
+            z >= -1;
+            range: -5 <= w - x <= 5;
+        };
+        assert_eq!(lp.vars.len(), 4);
+        assert_eq!(lp.constrs.len(), 3);
+        assert_eq!(lp.model.obj_sense(), ObjSense::Maximize);
+
+        let solved = lp.model.optimize();
+        assert_eq!(solved.status(), Status::Optimal);
+        let primal = solved.primal_solution();
+        let value = |name: &str| primal[lp.vars[name].col_id()];
+        assert!((value("x") - 4.0).abs() < 1e-6);
+        assert!((value("y") - 3.0).abs() < 1e-6);
+        assert!((value("z") + 1.0).abs() < 1e-6);
+        assert!((value("w") - value("x")).abs() <= 5.0 + 1e-6);
+        assert!((solved.obj_val() - 12.5).abs() < 1e-6);
+    }
+
+    macro_rules! box_lp {
+        ($($var:ident $constr:ident),*) => {
+            crate::lp! {
+                maximize $($var +)* 0;
+                subject to
+                $(
+                    $var in [0, 1];
+                    $constr: 2 * $var <= 1;
+                )*
+            }
+        };
+    }
+
+    #[test]
+    fn lp_macro_many_statements() {
+        let lp = box_lp!(
+            x0 c0, x1 c1, x2 c2, x3 c3, x4 c4, x5 c5, x6 c6, x7 c7, x8 c8, x9 c9,
+            x10 c10, x11 c11, x12 c12, x13 c13, x14 c14, x15 c15, x16 c16, x17 c17, x18 c18,
+            x19 c19, x20 c20, x21 c21, x22 c22, x23 c23, x24 c24, x25 c25, x26 c26, x27 c27,
+            x28 c28, x29 c29, x30 c30, x31 c31, x32 c32, x33 c33, x34 c34, x35 c35, x36 c36,
+            x37 c37, x38 c38, x39 c39
+        );
+        assert_eq!(lp.vars.len(), 40);
+        assert_eq!(lp.constrs.len(), 40);
+        assert_eq!(lp.constrs["c39"], 39);
+
+        let solved = lp.model.optimize();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 20.0).abs() < 1e-6);
+    }
+}
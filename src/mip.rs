@@ -0,0 +1,328 @@
+use crate::{Algorithm, ColId, Model, ObjSense, Status};
+use std::time::{Duration, Instant};
+
+/// How `BranchAndBound` picks the next open node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeSelection {
+    /// The most recently created node, which finds incumbents quickly and keeps the tree small.
+    DepthFirst,
+    /// The node with the best LP bound, which improves the global bound fastest.
+    BestBound,
+}
+
+/// Why a `BranchAndBound` stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MipStatus {
+    /// The incumbent is optimal within the gap tolerance.
+    Optimal,
+    /// No solution satisfies the integrality requirements.
+    Infeasible,
+    /// The node limit was reached.
+    NodeLimit,
+    /// The time limit was reached.
+    TimeLimit,
+    /// The LP of a node was neither optimal nor infeasible, e.g. unbounded.
+    LpNotSolved(Status),
+}
+
+/// Result of `BranchAndBound::run`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MipResult {
+    /// Why the search stopped.
+    pub status: MipStatus,
+    /// The best solution found that satisfies the integrality requirements.
+    pub incumbent: Option<Vec<f64>>,
+    /// The objective value of the incumbent, infinitely bad without one.
+    pub obj_val: f64,
+    /// The bound on the optimal objective value given by the open nodes.
+    pub bound: f64,
+    /// The gap `|obj_val - bound| / max(|obj_val|, 1)`, infinity without an incumbent.
+    pub gap: f64,
+    /// The number of LPs that were solved.
+    pub num_nodes: usize,
+}
+
+/// A node of the search tree.
+struct Node {
+    /// The bounds of the integer columns.
+    bounds: Vec<(f64, f64)>,
+    /// The LP bound of the parent, as a value to be minimized.
+    bound: f64,
+}
+
+/// LP-based branch-and-bound for models with a few integer columns.
+///
+/// Every node changes the bounds of the integer columns with `change_col_bounds` and reoptimizes
+/// the same model with the dual simplex, warm started from the basis of the previous node. It
+/// branches on the most fractional integer column.
+///
+/// # Example
+/// ```
+/// use soplex_rs::*;
+///
+/// // max x + y  s.t.  2x + 2y <= 3,  x, y integer
+/// let mut lp = Model::new();
+/// let x = lp.add_col(vec![], 1.0, 0.0, 10.0);
+/// let y = lp.add_col(vec![], 1.0, 0.0, 10.0);
+/// lp.add_row(vec![2.0, 2.0], f64::NEG_INFINITY, 3.0);
+/// lp.set_obj_sense(ObjSense::Maximize);
+///
+/// let result = BranchAndBound::new(lp, vec![x, y]).run();
+/// assert_eq!(result.status, MipStatus::Optimal);
+/// assert!((result.obj_val - 1.0).abs() < 1e-6);
+/// ```
+pub struct BranchAndBound {
+    model: Model,
+    integers: Vec<ColId>,
+    node_selection: NodeSelection,
+    node_limit: usize,
+    time_limit: Option<Duration>,
+    int_tol: f64,
+    gap_tol: f64,
+}
+
+impl BranchAndBound {
+    /// Creates the search.
+    ///
+    /// # Arguments
+    /// * `model` - The LP relaxation.
+    /// * `integers` - The columns that have to take integer values.
+    pub fn new(model: Model, integers: Vec<ColId>) -> Self {
+        Self {
+            model,
+            integers,
+            node_selection: NodeSelection::BestBound,
+            node_limit: usize::MAX,
+            time_limit: None,
+            int_tol: 1e-6,
+            gap_tol: 1e-6,
+        }
+    }
+
+    /// Sets how the next node is picked, best bound by default.
+    pub fn with_node_selection(mut self, node_selection: NodeSelection) -> Self {
+        self.node_selection = node_selection;
+        self
+    }
+
+    /// Sets the maximum number of LPs to solve, unlimited by default.
+    pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = node_limit;
+        self
+    }
+
+    /// Sets the time limit of the search, unlimited by default.
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Sets how far a value may be from an integer to count as integral, 1e-6 by default.
+    pub fn with_int_tol(mut self, int_tol: f64) -> Self {
+        self.int_tol = int_tol;
+        self
+    }
+
+    /// Sets the gap at which the incumbent counts as optimal, 1e-6 by default.
+    pub fn with_gap_tol(mut self, gap_tol: f64) -> Self {
+        self.gap_tol = gap_tol;
+        self
+    }
+
+    /// Runs the search until the incumbent is optimal or a limit is reached.
+    pub fn run(self) -> MipResult {
+        let start = Instant::now();
+        let mut model = self.model;
+        model.set_algorithm(Algorithm::Dual);
+        // objective values are negated when maximizing so that they are always minimized
+        let sign = match model.obj_sense() {
+            ObjSense::Minimize => 1.0,
+            ObjSense::Maximize => -1.0,
+        };
+        let data = model.lp_data();
+        let original: Vec<(f64, f64)> = self
+            .integers
+            .iter()
+            .map(|&col_id| data.col_bounds[col_id])
+            .collect();
+
+        let mut current = original.clone();
+        let mut open = vec![Node {
+            bounds: original,
+            bound: f64::NEG_INFINITY,
+        }];
+        let mut incumbent: Option<Vec<f64>> = None;
+        let mut incumbent_val = f64::INFINITY;
+        let mut num_nodes = 0;
+        let cutoff =
+            |incumbent_val: f64| incumbent_val - self.gap_tol * incumbent_val.abs().max(1.0);
+
+        let status = loop {
+            let idx = match self.node_selection {
+                NodeSelection::DepthFirst => open.len().checked_sub(1),
+                NodeSelection::BestBound => {
+                    (0..open.len()).min_by(|&a, &b| open[a].bound.total_cmp(&open[b].bound))
+                }
+            };
+            let Some(idx) = idx else {
+                break if incumbent.is_some() {
+                    MipStatus::Optimal
+                } else {
+                    MipStatus::Infeasible
+                };
+            };
+            if open[idx].bound >= cutoff(incumbent_val) {
+                open.swap_remove(idx);
+                continue;
+            }
+            if num_nodes >= self.node_limit {
+                break MipStatus::NodeLimit;
+            }
+            if self
+                .time_limit
+                .is_some_and(|limit| start.elapsed() >= limit)
+            {
+                break MipStatus::TimeLimit;
+            }
+            let node = open.swap_remove(idx);
+
+            for (i, (&col_id, &bounds)) in self.integers.iter().zip(&node.bounds).enumerate() {
+                if current[i] != bounds {
+                    model.change_col_bounds(col_id, bounds.0, bounds.1);
+                    current[i] = bounds;
+                }
+            }
+            let solved = model.optimize();
+            num_nodes += 1;
+            let status = solved.status();
+            let lp_val = sign * solved.obj_val();
+            let primal = solved.primal_solution();
+            model = Model::from(solved);
+            match status {
+                Status::Optimal => {}
+                Status::Infeasible => continue,
+                status => {
+                    open.push(node);
+                    break MipStatus::LpNotSolved(status);
+                }
+            }
+            if lp_val >= cutoff(incumbent_val) {
+                continue;
+            }
+
+            // the most fractional integer column
+            let branch = self
+                .integers
+                .iter()
+                .enumerate()
+                .map(|(i, &col_id)| {
+                    (
+                        i,
+                        primal[col_id],
+                        (primal[col_id] - primal[col_id].round()).abs(),
+                    )
+                })
+                .filter(|&(_, _, frac)| frac > self.int_tol)
+                .max_by(|a, b| a.2.total_cmp(&b.2));
+            let Some((i, value, _)) = branch else {
+                incumbent_val = lp_val;
+                incumbent = Some(primal);
+                continue;
+            };
+
+            let (lb, ub) = node.bounds[i];
+            let mut down = node.bounds.clone();
+            down[i] = (lb, value.floor());
+            let mut up = node.bounds;
+            up[i] = (value.ceil(), ub);
+            // depth-first search continues with the child on the side the value is closer to
+            let children = if value - value.floor() < 0.5 {
+                [up, down]
+            } else {
+                [down, up]
+            };
+            open.extend(children.into_iter().map(|bounds| Node {
+                bounds,
+                bound: lp_val,
+            }));
+        };
+
+        let bound = match status {
+            MipStatus::Optimal => incumbent_val,
+            MipStatus::Infeasible => f64::INFINITY,
+            _ => open
+                .iter()
+                .map(|node| node.bound)
+                .fold(incumbent_val, f64::min),
+        };
+        let gap = if incumbent.is_some() {
+            (incumbent_val - bound).abs() / incumbent_val.abs().max(1.0)
+        } else {
+            f64::INFINITY
+        };
+        MipResult {
+            status,
+            incumbent,
+            obj_val: sign * incumbent_val,
+            bound: sign * bound,
+            gap,
+            num_nodes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Knapsack with capacity 10: values 10, 13, 7, 8 and weights 5, 6, 4, 3.
+    fn knapsack() -> (Model, Vec<ColId>) {
+        let mut lp = Model::new();
+        let cols: Vec<ColId> = [10.0, 13.0, 7.0, 8.0]
+            .into_iter()
+            .map(|value| lp.add_col(vec![], value, 0.0, 1.0))
+            .collect();
+        lp.add_row(vec![5.0, 6.0, 4.0, 3.0], f64::NEG_INFINITY, 10.0);
+        lp.set_obj_sense(ObjSense::Maximize);
+        (lp, cols)
+    }
+
+    #[test]
+    fn branch_and_bound() {
+        for node_selection in [NodeSelection::DepthFirst, NodeSelection::BestBound] {
+            let (lp, cols) = knapsack();
+            let result = BranchAndBound::new(lp, cols)
+                .with_node_selection(node_selection)
+                .run();
+            assert_eq!(result.status, MipStatus::Optimal);
+            // items 2 and 4
+            assert!((result.obj_val - 21.0).abs() < 1e-6);
+            assert!(result.gap < 1e-6);
+            let incumbent = result.incumbent.unwrap();
+            assert!((incumbent[1] - 1.0).abs() < 1e-6 && (incumbent[3] - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn branch_and_bound_infeasible() {
+        // 2x == 1 has no integer solution
+        let mut lp = Model::new();
+        let x = lp.add_col(vec![], 1.0, 0.0, 10.0);
+        lp.add_row(vec![2.0], 1.0, 1.0);
+        let result = BranchAndBound::new(lp, vec![x]).run();
+        assert_eq!(result.status, MipStatus::Infeasible);
+        assert_eq!(result.incumbent, None);
+        assert_eq!(result.gap, f64::INFINITY);
+    }
+
+    #[test]
+    fn branch_and_bound_node_limit() {
+        let (lp, cols) = knapsack();
+        let result = BranchAndBound::new(lp, cols).with_node_limit(1).run();
+        assert_eq!(result.status, MipStatus::NodeLimit);
+        assert_eq!(result.num_nodes, 1);
+        // the root LP bound
+        assert!(result.bound >= 21.0 - 1e-6);
+    }
+}
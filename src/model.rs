@@ -1,13 +1,23 @@
 use crate::param::{ALGORITHM_PARAM_ID, OBJSENSE_PARAM_ID, REPR_PARAM_ID};
+use crate::parameters::{private, NUM_BOOL_PARAMS, NUM_INT_PARAMS, NUM_REAL_PARAMS};
 use crate::soplex_ptr::SoplexPtr;
 use crate::status::Status;
 use crate::{
-    ffi, BoolParam, ColBasisStatus, IntParam, ObjSense, RealParam, RowBasisStatus, Verbosity,
+    ffi, Algorithm, BoolParam, CheckMode, ColBasisStatus, Constraint, IntParam, LinExpr,
+    ModelStats, ObjSense, Param, ParamError, Parameters, Preset, Pricer, RatioTester, RealParam,
+    Representation, RowBasisStatus, Scalar, SettingsError, Simplifier, Solution, SolveMode,
+    SolveStatistics, Starter, Var, Verbosity,
 };
 
+/// Tolerance used to decide whether a value sits at a bound or a reduced cost is zero.
+const ZERO_TOL: f64 = 1e-6;
+
 /// A linear programming model.
 pub struct Model {
     inner: SoplexPtr,
+    params: Parameters,
+    /// Dense row passed to SoPlex by `add_constr`, all zero between calls.
+    row_buffer: Vec<f64>,
 }
 
 /// Id of a row in the model.
@@ -39,6 +49,8 @@ impl Model {
     pub fn new() -> Self {
         Self {
             inner: SoplexPtr::new(),
+            params: Parameters::new(),
+            row_buffer: Vec::new(),
         }
     }
 
@@ -102,10 +114,86 @@ impl Model {
         self.num_rows() - 1
     }
 
+    /// Adds a variable (an empty column) to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `objval` - The objective value of the variable.
+    /// * `lb` - The lower bound of the variable.
+    /// * `ub` - The upper bound of the variable.
+    ///
+    /// # Returns
+    ///
+    /// The `Var` handle of the added variable, to be used in `LinExpr`s.
+    pub fn add_var(&mut self, objval: f64, lb: f64, ub: f64) -> Var {
+        Var(self.add_col(vec![], objval, lb, ub))
+    }
+
+    /// Adds a constraint built from a `LinExpr`, e.g. `model.add_constr((x + 2.0 * y).le(5.0))`.
+    ///
+    /// Constant terms of the expression have already been folded into the sides of the constraint.
+    ///
+    /// # Arguments
+    ///
+    /// * `constr` - The `Constraint` to add.
+    ///
+    /// # Returns
+    ///
+    /// The `RowId` of the added row.
+    pub fn add_constr(&mut self, constr: Constraint) -> RowId {
+        let coefs = constr.expr().coefs();
+        let len = coefs.last().map_or(0, |&(col_id, _)| col_id + 1);
+        assert!(
+            len <= self.num_cols(),
+            "constraint refers to a variable that is not part of the model"
+        );
+        // SoPlex only takes dense rows, only the prefix up to the last variable in the row is
+        // passed and the entries are written to and cleared from a buffer kept between calls
+        if self.row_buffer.len() < len {
+            self.row_buffer.resize(len, 0.0);
+        }
+        for &(col_id, coef) in &coefs {
+            self.row_buffer[col_id] = coef;
+        }
+        unsafe {
+            ffi::SoPlex_addRowReal(
+                *self.inner,
+                self.row_buffer.as_mut_ptr(),
+                len as i32,
+                coefs.len() as i32,
+                constr.lhs(),
+                constr.rhs(),
+            );
+        }
+        for &(col_id, _) in &coefs {
+            self.row_buffer[col_id] = 0.0;
+        }
+        self.num_rows() - 1
+    }
+
+    /// Sets the objective function from a `LinExpr`, the constant term becomes the objective offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The objective function, variables that do not appear get an objective value of 0.
+    /// * `sense` - The objective sense.
+    pub fn set_objective(&mut self, expr: &LinExpr, sense: ObjSense) {
+        assert!(
+            expr.col_bound() <= self.num_cols(),
+            "objective refers to a variable that is not part of the model"
+        );
+        self.set_obj_vals(&expr.dense_coefs(self.num_cols()));
+        self.set_real_param(RealParam::ObjOffset, expr.constant());
+        self.set_obj_sense(sense);
+    }
+
     /// Optimizes the model and returns the solved model.
     pub fn optimize(self) -> SolvedModel {
         unsafe { ffi::SoPlex_optimize(*self.inner) };
-        SolvedModel { inner: self.inner }
+        SolvedModel {
+            inner: self.inner,
+            params: self.params,
+        }
     }
 
     /// Returns the number of columns in the model.
@@ -157,10 +245,13 @@ impl Model {
     /// # Arguments
     /// * `param` - which `BoolParam` to set.
     /// * `value` - The value of the parameter.
+    ///
+    /// The value is passed to SoPlex as is, which ignores values that are not valid for the
+    /// parameter. Use `set_param` to get an error for those instead.
     pub fn set_bool_param(&mut self, param: BoolParam, value: bool) {
-        unsafe {
-            ffi::SoPlex_setBoolParam(*self.inner, param.into(), value as i32);
-        }
+        private::Value::apply(value, *self.inner, param.into());
+        // invalid values are rejected by SoPlex as well, so the snapshot stays in sync
+        let _ = self.params.set(param, value);
     }
 
     /// Sets integer parameter.
@@ -168,10 +259,13 @@ impl Model {
     /// # Arguments
     /// * `param` - which `IntParam` to set.
     /// * `value` - The value of the parameter.
+    ///
+    /// The value is passed to SoPlex as is, which ignores values that are not valid for the
+    /// parameter. Use `set_param` to get an error for those instead.
     pub fn set_int_param(&mut self, param: IntParam, value: i32) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, param.into(), value);
-        }
+        private::Value::apply(value, *self.inner, param.into());
+        // invalid values are rejected by SoPlex as well, so the snapshot stays in sync
+        let _ = self.params.set(param, value);
     }
 
     /// Sets real parameter.
@@ -179,10 +273,100 @@ impl Model {
     /// # Arguments
     /// * `param` - which `RealParam` to set.
     /// * `value` - The value of the parameter.
+    ///
+    /// The value is passed to SoPlex as is, which ignores values that are not valid for the
+    /// parameter. Use `set_param` to get an error for those instead.
     pub fn set_real_param(&mut self, param: RealParam, value: f64) {
-        unsafe {
-            ffi::SoPlex_setRealParam(*self.inner, param.into(), value);
+        private::Value::apply(value, *self.inner, param.into());
+        // invalid values are rejected by SoPlex as well, so the snapshot stays in sync
+        let _ = self.params.set(param, value);
+    }
+
+    /// Returns the value of a parameter.
+    ///
+    /// # Arguments
+    /// * `param` - The `BoolParam`, `IntParam` or `RealParam` to read.
+    pub fn param<P: Param>(&self, param: P) -> P::Value {
+        self.params.get(param)
+    }
+
+    /// Sets the value of a parameter.
+    ///
+    /// # Arguments
+    /// * `param` - The `BoolParam`, `IntParam` or `RealParam` to set.
+    /// * `value` - The value of the parameter.
+    ///
+    /// # Errors
+    /// if the value is outside of the valid range of the parameter, the model is left unchanged then.
+    pub fn set_param<P: Param>(&mut self, param: P, value: P::Value) -> Result<(), ParamError> {
+        self.params.set(param, value)?;
+        private::Value::apply(value, *self.inner, param.into());
+        Ok(())
+    }
+
+    /// Returns a snapshot of all parameters of the model.
+    pub fn parameters(&self) -> Parameters {
+        let mut params = self.params.clone();
+        // the objective sense may also be changed by reading an instance file
+        params.set_obj_sense(self.obj_sense());
+        params
+    }
+
+    /// Applies all parameters of a snapshot to the model.
+    ///
+    /// # Arguments
+    /// * `params` - The `Parameters` to apply.
+    pub fn set_parameters(&mut self, params: &Parameters) {
+        let current = self.parameters();
+        for code in 0..NUM_BOOL_PARAMS {
+            if params.bools[code] != current.bools[code] {
+                private::Value::apply(params.bools[code], *self.inner, code as i32);
+            }
+        }
+        for code in 0..NUM_INT_PARAMS {
+            if params.ints[code] != current.ints[code] {
+                private::Value::apply(params.ints[code], *self.inner, code as i32);
+            }
         }
+        for code in 0..NUM_REAL_PARAMS {
+            if params.reals[code] != current.reals[code] {
+                private::Value::apply(params.reals[code], *self.inner, code as i32);
+            }
+        }
+        self.params = params.clone();
+    }
+
+    /// Reads parameters from a SoPlex settings file and applies them to the model.
+    ///
+    /// # Arguments
+    /// * `filename` - The name of the settings file, lines have the form `real:feastol = 1e-9`.
+    ///
+    /// # Returns
+    /// The skipped lines with unknown parameters, see `Parameters::apply_settings`.
+    ///
+    /// # Errors
+    /// if the file can not be read or contains an invalid line, the model is left unchanged then.
+    pub fn read_settings(&mut self, filename: &str) -> Result<Vec<SettingsError>, SettingsError> {
+        let settings = std::fs::read_to_string(filename)?;
+        let mut params = self.parameters();
+        let skipped = params.apply_settings(&settings)?;
+        self.set_parameters(&params);
+        Ok(skipped)
+    }
+
+    /// Writes the parameters of the model to a SoPlex settings file.
+    ///
+    /// # Arguments
+    /// * `filename` - The name of the settings file to write.
+    /// * `only_changed` - If true, only parameters that differ from their default value are written.
+    pub fn write_settings(&self, filename: &str, only_changed: bool) -> std::io::Result<()> {
+        std::fs::write(filename, self.parameters().to_settings_string(only_changed))
+    }
+
+    /// Sets an integer parameter by its SoPlex code and keeps the parameter snapshot in sync.
+    fn set_int_code(&mut self, code: i32, value: i32) {
+        unsafe { ffi::SoPlex_setIntParam(*self.inner, code, value) };
+        self.params.ints[code as usize] = value;
     }
 
     /// Change the bounds of a column.
@@ -214,9 +398,7 @@ impl Model {
     /// # Arguments
     /// * `sense` - The objective sense of the model.
     pub fn set_obj_sense(&mut self, sense: ObjSense) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, OBJSENSE_PARAM_ID, sense.into());
-        }
+        self.set_int_code(OBJSENSE_PARAM_ID, sense.into());
     }
 
     /// Sets the algorithm to use.
@@ -224,9 +406,7 @@ impl Model {
     /// # Arguments
     /// * `algorithm` - The `Algorithm` to use.
     pub fn set_algorithm(&mut self, algorithm: crate::Algorithm) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, ALGORITHM_PARAM_ID, algorithm.into());
-        }
+        self.set_int_code(ALGORITHM_PARAM_ID, algorithm.into());
     }
 
     /// Sets the representation of the model.
@@ -234,9 +414,7 @@ impl Model {
     /// # Arguments
     /// * `representation` - The `Representation` of the model.
     pub fn set_representation(&mut self, representation: crate::Representation) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, REPR_PARAM_ID, representation.into());
-        }
+        self.set_int_code(REPR_PARAM_ID, representation.into());
     }
 
     /// Sets the verbosity level.
@@ -244,9 +422,7 @@ impl Model {
     /// # Arguments
     /// * `verbosity` - The verbosity level.
     pub fn set_verbosity(&mut self, verbosity: Verbosity) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::VERBOSITY_PARAM_ID, verbosity.into());
-        }
+        self.set_int_code(crate::VERBOSITY_PARAM_ID, verbosity.into());
     }
 
     /// Sets the factor update type.
@@ -254,13 +430,10 @@ impl Model {
     /// # Arguments
     /// * `factor_update_type` - The factor update type.
     pub fn set_factor_update_type(&mut self, factor_update_type: crate::FactorUpdateType) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::FACTOR_UPDATE_TYPE_PARAM_ID,
-                factor_update_type.into(),
-            );
-        }
+        self.set_int_code(
+            crate::FACTOR_UPDATE_TYPE_PARAM_ID,
+            factor_update_type.into(),
+        );
     }
 
     /// Sets the simplifier type.
@@ -268,13 +441,7 @@ impl Model {
     /// # Arguments
     /// * `simplifier_type` - The simplifier type.
     pub fn set_simplifier_type(&mut self, simplifier_type: crate::Simplifier) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::SIMPLIFIER_PARAM_ID,
-                simplifier_type.into(),
-            );
-        }
+        self.set_int_code(crate::SIMPLIFIER_PARAM_ID, simplifier_type.into());
     }
 
     /// Sets the starter type.
@@ -282,9 +449,7 @@ impl Model {
     /// # Arguments
     /// * `starter_type` - The starter type.
     pub fn set_starter_type(&mut self, starter_type: crate::Starter) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::STARTER_PARAM_ID, starter_type.into());
-        }
+        self.set_int_code(crate::STARTER_PARAM_ID, starter_type.into());
     }
 
     /// Sets the pricer type.
@@ -292,9 +457,7 @@ impl Model {
     /// # Arguments
     /// * `pricer_type` - The pricer type.
     pub fn set_pricer_type(&mut self, pricer_type: crate::Pricer) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::PRICER_PARAM_ID, pricer_type.into());
-        }
+        self.set_int_code(crate::PRICER_PARAM_ID, pricer_type.into());
     }
 
     /// Sets the ratio tester type.
@@ -302,13 +465,7 @@ impl Model {
     /// # Arguments
     /// * `ratio_tester_type` - The ratio tester type.
     pub fn set_ratio_tester_type(&mut self, ratio_tester_type: crate::RatioTester) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::RATIO_TESTER_PARAM_ID,
-                ratio_tester_type.into(),
-            );
-        }
+        self.set_int_code(crate::RATIO_TESTER_PARAM_ID, ratio_tester_type.into());
     }
 
     /// Sets the sync mode.
@@ -316,9 +473,7 @@ impl Model {
     /// # Arguments
     /// * `sync_mode` - The sync mode.
     pub fn set_sync_mode(&mut self, sync_mode: crate::SyncMode) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::SYNC_MODE_PARAM_ID, sync_mode.into());
-        }
+        self.set_int_code(crate::SYNC_MODE_PARAM_ID, sync_mode.into());
     }
 
     /// Sets the read mode.
@@ -326,9 +481,7 @@ impl Model {
     /// # Arguments
     /// * `read_mode` - The read mode.
     pub fn set_read_mode(&mut self, read_mode: crate::ReadMode) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::READ_MODE_PARAM_ID, read_mode.into());
-        }
+        self.set_int_code(crate::READ_MODE_PARAM_ID, read_mode.into());
     }
 
     /// Sets the solve mode.
@@ -336,9 +489,7 @@ impl Model {
     /// # Arguments
     /// * `solve_mode` - The solve mode.
     pub fn set_solve_mode(&mut self, solve_mode: crate::SolveMode) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::SOLVE_MODE_PARAM_ID, solve_mode.into());
-        }
+        self.set_int_code(crate::SOLVE_MODE_PARAM_ID, solve_mode.into());
     }
 
     /// Sets the check mode.
@@ -346,9 +497,7 @@ impl Model {
     /// # Arguments
     /// * `check_mode` - The check mode.
     pub fn set_check_mode(&mut self, check_mode: crate::CheckMode) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::CHECK_MODE_PARAM_ID, check_mode.into());
-        }
+        self.set_int_code(crate::CHECK_MODE_PARAM_ID, check_mode.into());
     }
 
     /// Sets the timer mode.
@@ -356,9 +505,7 @@ impl Model {
     /// # Arguments
     /// * `timer_mode` - The timer mode.
     pub fn set_timer_mode(&mut self, timer_mode: crate::Timer) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::TIMER_PARAM_ID, timer_mode.into());
-        }
+        self.set_int_code(crate::TIMER_PARAM_ID, timer_mode.into());
     }
 
     /// Sets the hyper pricing parameter.
@@ -366,13 +513,7 @@ impl Model {
     /// # Arguments
     /// * `hyper_pricing` - The hyper pricing parameter.
     pub fn set_hyper_pricing(&mut self, hyper_pricing: crate::HyperPricing) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::HYPER_PRICING_PARAM_ID,
-                hyper_pricing.into(),
-            );
-        }
+        self.set_int_code(crate::HYPER_PRICING_PARAM_ID, hyper_pricing.into());
     }
 
     /// Sets the solution polishing type.
@@ -380,27 +521,10 @@ impl Model {
     /// # Arguments
     /// * `solution_polishing` - The solution polishing type.
     pub fn set_solution_polishing(&mut self, solution_polishing: crate::SolutionPolishing) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::SOLUTION_POLISHING_PARAM_ID,
-                solution_polishing.into(),
-            );
-        }
-    }
-
-    /// Sets the decomposition verbosity.
-    ///
-    /// # Arguments
-    /// * `decomp_verbosity` - The decomposition verbosity.
-    pub fn set_decomp_verbosity(&mut self, decomp_verbosity: crate::Verbosity) {
-        unsafe {
-            ffi::SoPlex_setIntParam(
-                *self.inner,
-                crate::DECOMP_VERBOSITY_PARAM_ID,
-                decomp_verbosity.into(),
-            );
-        }
+        self.set_int_code(
+            crate::SOLUTION_POLISHING_PARAM_ID,
+            solution_polishing.into(),
+        );
     }
 
     /// Sets the statistics timer parameter.
@@ -408,9 +532,7 @@ impl Model {
     /// # Arguments
     /// * `stat_timer` - The statistics timer parameter.
     pub fn set_stat_timer(&mut self, stat_timer: crate::Timer) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::STAT_TIMER_PARAM_ID, stat_timer.into());
-        }
+        self.set_int_code(crate::STAT_TIMER_PARAM_ID, stat_timer.into());
     }
 
     /// Sets the scalar type.
@@ -418,8 +540,44 @@ impl Model {
     /// # Arguments
     /// * `scalar_type` - The scalar type.
     pub fn set_scalar_type(&mut self, scalar_type: crate::Scalar) {
-        unsafe {
-            ffi::SoPlex_setIntParam(*self.inner, crate::SCALAR_PARAM_ID, scalar_type.into());
+        self.set_int_code(crate::SCALAR_PARAM_ID, scalar_type.into());
+    }
+
+    /// Applies a bundle of parameters for a common use case, see `Preset` for the parameters changed by each.
+    ///
+    /// # Arguments
+    /// * `preset` - The `Preset` to apply.
+    pub fn apply_preset(&mut self, preset: Preset) {
+        match preset {
+            Preset::Fast => {
+                self.set_simplifier_type(Simplifier::Internal);
+                self.set_scalar_type(Scalar::Biequi);
+                self.set_pricer_type(Pricer::Devex);
+                self.set_ratio_tester_type(RatioTester::Fast);
+                self.set_algorithm(Algorithm::Dual);
+                self.set_solve_mode(SolveMode::Real);
+                self.set_check_mode(CheckMode::Real);
+                self.set_real_param(RealParam::FeasTol, 1e-6);
+                self.set_real_param(RealParam::OptTol, 1e-6);
+            }
+            Preset::NumericallySafe => {
+                self.set_simplifier_type(Simplifier::Internal);
+                self.set_scalar_type(Scalar::Leastsq);
+                self.set_pricer_type(Pricer::Steep);
+                self.set_ratio_tester_type(RatioTester::Harris);
+                self.set_algorithm(Algorithm::Dual);
+                self.set_solve_mode(SolveMode::Real);
+                self.set_check_mode(CheckMode::Auto);
+                self.set_real_param(RealParam::FeasTol, 1e-9);
+                self.set_real_param(RealParam::OptTol, 1e-9);
+            }
+            Preset::WarmStartFriendly => {
+                self.set_simplifier_type(Simplifier::Off);
+                self.set_starter_type(Starter::Off);
+                self.set_algorithm(Algorithm::Dual);
+                self.set_representation(Representation::Auto);
+                self.set_solve_mode(SolveMode::Real);
+            }
         }
     }
 
@@ -427,27 +585,224 @@ impl Model {
     ///
     /// # Arguments
     /// * `objvals` - The objective function vector.
-    pub fn set_obj_vals(&mut self, objvals: &mut [f64]) {
+    pub fn set_obj_vals(&mut self, objvals: &[f64]) {
         let num_cols = self.num_cols();
         assert_eq!(
             objvals.len(),
             num_cols,
             "objvals must have the same length as the number of columns"
         );
+        let mut objvals = objvals.to_vec();
+        unsafe {
+            ffi::SoPlex_changeObjReal(*self.inner, objvals.as_mut_ptr(), num_cols as i32);
+        }
+    }
+
+    /// Sets the bounds of all columns in one call.
+    ///
+    /// # Arguments
+    /// * `lb` - The lower bound of every column.
+    /// * `ub` - The upper bound of every column.
+    pub fn set_col_bounds_all(&mut self, lb: &[f64], ub: &[f64]) {
+        let num_cols = self.num_cols();
+        assert!(
+            lb.len() == num_cols && ub.len() == num_cols,
+            "lb and ub must have the same length as the number of columns"
+        );
+        let (mut lb, mut ub) = (lb.to_vec(), ub.to_vec());
         unsafe {
-            ffi::SoPlex_changeObjReal(*self.inner, objvals.as_mut_ptr(), objvals.len() as i32);
+            ffi::SoPlex_changeBoundsReal(
+                *self.inner,
+                lb.as_mut_ptr(),
+                ub.as_mut_ptr(),
+                num_cols as i32,
+            );
+        }
+    }
+
+    /// Sets the left- and right-hand sides of all rows in one call.
+    ///
+    /// # Arguments
+    /// * `lhs` - The left-hand side of every row.
+    /// * `rhs` - The right-hand side of every row.
+    pub fn set_row_ranges_all(&mut self, lhs: &[f64], rhs: &[f64]) {
+        let num_rows = self.num_rows();
+        assert!(
+            lhs.len() == num_rows && rhs.len() == num_rows,
+            "lhs and rhs must have the same length as the number of rows"
+        );
+        let (mut lhs, mut rhs) = (lhs.to_vec(), rhs.to_vec());
+        unsafe {
+            ffi::SoPlex_changeRangeReal(
+                *self.inner,
+                lhs.as_mut_ptr(),
+                rhs.as_mut_ptr(),
+                num_rows as i32,
+            );
+        }
+    }
+
+    /// Changes the bounds of some columns in one call, the other columns keep their bounds.
+    ///
+    /// # Arguments
+    /// * `updates` - The new lower and upper bound of every column to change.
+    ///
+    /// # Panics
+    /// if a `ColId` is out of range.
+    pub fn update_col_bounds(&mut self, updates: &[(ColId, f64, f64)]) {
+        let (mut lb, mut ub) = col_bounds(&self.inner);
+        for &(col_id, lower, upper) in updates {
+            assert!(col_id < lb.len(), "column {} is out of range", col_id);
+            lb[col_id] = lower;
+            ub[col_id] = upper;
+        }
+        self.set_col_bounds_all(&lb, &ub);
+    }
+
+    /// Changes the sides of some rows, the other rows keep their sides.
+    ///
+    /// SoPlex can only read the sides row by row, so fewer updates than rows are applied one by
+    /// one and only larger ones in one call.
+    ///
+    /// # Arguments
+    /// * `updates` - The new left- and right-hand side of every row to change.
+    ///
+    /// # Panics
+    /// if a `RowId` is out of range.
+    pub fn update_row_ranges(&mut self, updates: &[(RowId, f64, f64)]) {
+        let num_rows = self.num_rows();
+        for &(row_id, _, _) in updates {
+            assert!(row_id < num_rows, "row {} is out of range", row_id);
+        }
+        if updates.len() < num_rows {
+            for &(row_id, lower, upper) in updates {
+                self.change_row_range(row_id, lower, upper);
+            }
+            return;
+        }
+
+        let (mut lhs, mut rhs): (Vec<f64>, Vec<f64>) = (0..num_rows)
+            .map(|row_id| row_range(&self.inner, row_id))
+            .unzip();
+        for &(row_id, lower, upper) in updates {
+            lhs[row_id] = lower;
+            rhs[row_id] = upper;
         }
+        self.set_row_ranges_all(&lhs, &rhs);
     }
 
     /// Gets the objective sense of the model.
     pub fn obj_sense(&self) -> ObjSense {
         unsafe { ffi::SoPlex_getIntParam(*self.inner, OBJSENSE_PARAM_ID) }.into()
     }
+
+    /// Returns statistics about the data of the model, see `ModelStats`.
+    ///
+    /// Its `Display` implementation prints a summary with the coefficient ranges and the warnings.
+    pub fn stats(&self) -> ModelStats {
+        ModelStats::new(&self.lp_data())
+    }
+
+    /// Returns a copy of the data of the model.
+    pub(crate) fn lp_data(&self) -> LpData {
+        LpData::new(&self.inner, &self.params)
+    }
+
+    /// Builds a model from sparse vectors. All columns (or rows) are created first, the vectors
+    /// of the other orientation are then passed to SoPlex from a single reused dense buffer.
+    ///
+    /// # Arguments
+    /// * `obj` - The objective value of every column.
+    /// * `col_bounds` - The lower and upper bound of every column.
+    /// * `row_ranges` - The left- and right-hand side of every row.
+    /// * `by_rows` - Whether `vectors` yields the rows or the columns of the constraint matrix.
+    /// * `vectors` - The entries of every row (column) as `(col_id, value)` (`(row_id, value)`) pairs.
+    pub(crate) fn load<V: IntoIterator<Item = (usize, f64)>>(
+        obj: &[f64],
+        col_bounds: &[(f64, f64)],
+        row_ranges: &[(f64, f64)],
+        by_rows: bool,
+        vectors: impl IntoIterator<Item = V>,
+    ) -> Model {
+        assert_eq!(
+            obj.len(),
+            col_bounds.len(),
+            "obj and col_bounds must have the same length"
+        );
+        let mut model = Model::new();
+        let dim = if by_rows {
+            for (&objval, &(lb, ub)) in obj.iter().zip(col_bounds) {
+                model.add_col(vec![], objval, lb, ub);
+            }
+            col_bounds.len()
+        } else {
+            for &(lhs, rhs) in row_ranges {
+                model.add_row(vec![], lhs, rhs);
+            }
+            row_ranges.len()
+        };
+
+        let mut dense = vec![0.0; dim];
+        let mut touched = Vec::new();
+        for (idx, vector) in vectors.into_iter().enumerate() {
+            for (i, value) in vector {
+                dense[i] += value;
+                touched.push(i);
+            }
+            touched.sort_unstable();
+            touched.dedup();
+            let nnonzeros = touched.iter().filter(|&&i| dense[i] != 0.0).count();
+            unsafe {
+                if by_rows {
+                    let (lhs, rhs) = row_ranges[idx];
+                    ffi::SoPlex_addRowReal(
+                        *model.inner,
+                        dense.as_mut_ptr(),
+                        dim as i32,
+                        nnonzeros as i32,
+                        lhs,
+                        rhs,
+                    );
+                } else {
+                    let (lb, ub) = col_bounds[idx];
+                    ffi::SoPlex_addColReal(
+                        *model.inner,
+                        dense.as_mut_ptr(),
+                        dim as i32,
+                        nnonzeros as i32,
+                        obj[idx],
+                        lb,
+                        ub,
+                    );
+                }
+            }
+            for i in touched.drain(..) {
+                dense[i] = 0.0;
+            }
+        }
+        model
+    }
+
+    /// Returns the nonzero entries of the constraint matrix as `(row_id, col_id, value)`, row by row.
+    #[cfg(any(feature = "sprs", feature = "nalgebra-sparse"))]
+    pub(crate) fn matrix_entries(&self) -> Vec<(RowId, ColId, f64)> {
+        let mut reader = RowReader::new(&self.inner);
+        let mut entries = Vec::new();
+        for row_id in 0..self.num_rows() {
+            entries.extend(
+                reader
+                    .row(row_id)
+                    .map(|(col_id, value)| (row_id, col_id, value)),
+            );
+        }
+        entries
+    }
 }
 
 /// A solved linear programming model.
 pub struct SolvedModel {
     inner: SoplexPtr,
+    params: Parameters,
 }
 
 impl SolvedModel {
@@ -471,6 +826,11 @@ impl SolvedModel {
         unsafe { ffi::SoPlex_objValueReal(*self.inner) }
     }
 
+    /// Gets the objective sense of the model.
+    pub fn obj_sense(&self) -> ObjSense {
+        unsafe { ffi::SoPlex_getIntParam(*self.inner, OBJSENSE_PARAM_ID) }.into()
+    }
+
     /// Returns the primal solution of the model.
     pub fn primal_solution(&self) -> Vec<f64> {
         let mut primal = vec![0.0; self.num_cols()];
@@ -481,6 +841,9 @@ impl SolvedModel {
     }
 
     /// Returns the dual solution of the model.
+    ///
+    /// Every value is the shadow price of the active side of its row, see `duals_normalized` for
+    /// multipliers of the individual sides.
     pub fn dual_solution(&self) -> Vec<f64> {
         let mut dual = vec![0.0; self.num_rows()];
         unsafe {
@@ -495,6 +858,8 @@ impl SolvedModel {
     }
 
     /// Returns the reduced costs of the model.
+    ///
+    /// Every value is the shadow price of the active bound of its column, see `duals_normalized`.
     pub fn reduced_costs(&self) -> Vec<f64> {
         let mut redcosts = vec![0.0; self.num_cols()];
         unsafe {
@@ -529,12 +894,276 @@ impl SolvedModel {
     pub fn row_basis_status(&self, row_id: RowId) -> RowBasisStatus {
         unsafe { ffi::SoPlex_basisRowStatus(*self.inner, row_id as i32) }.into()
     }
+
+    /// Returns a snapshot of the solution that does not borrow the model, see `Solution`.
+    pub fn solution(&self) -> Solution {
+        Solution::from(self)
+    }
+
+    /// Returns a copy of the data of the model.
+    pub(crate) fn lp_data(&self) -> LpData {
+        LpData::new(&self.inner, &self.params)
+    }
+
+    /// Returns the activity of every row in the primal solution.
+    pub(crate) fn row_activities(&self) -> Vec<f64> {
+        let primal = self.primal_solution();
+        let mut reader = RowReader::new(&self.inner);
+        (0..self.num_rows())
+            .map(|row_id| {
+                reader
+                    .row(row_id)
+                    .map(|(col_id, coef)| coef * primal[col_id])
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Returns the objective function vector.
+    pub(crate) fn obj_vals(&self) -> Vec<f64> {
+        let num_cols = self.num_cols();
+        let mut objvals = vec![0.0; num_cols];
+        unsafe { ffi::SoPlex_getObjReal(*self.inner, objvals.as_mut_ptr(), num_cols as i32) };
+        objvals
+    }
+
+    /// Changes the objective function vector, the solution is outdated until `reoptimize` is called.
+    pub(crate) fn change_obj_vals(&mut self, objvals: &[f64]) {
+        let mut objvals = objvals.to_vec();
+        unsafe {
+            ffi::SoPlex_changeObjReal(*self.inner, objvals.as_mut_ptr(), objvals.len() as i32)
+        };
+    }
+
+    /// Returns the left- and right-hand sides of all rows.
+    pub(crate) fn row_ranges(&self) -> Vec<(f64, f64)> {
+        (0..self.num_rows())
+            .map(|row_id| row_range(&self.inner, row_id))
+            .collect()
+    }
+
+    /// Changes the range of a row, the solution is outdated until `reoptimize` is called.
+    pub(crate) fn change_row_range(&mut self, row_id: RowId, lhs: f64, rhs: f64) {
+        unsafe { ffi::SoPlex_changeRowRangeReal(*self.inner, row_id as i32, lhs, rhs) };
+    }
+
+    /// Optimizes the model again, warm started from the current basis.
+    pub(crate) fn reoptimize(&mut self) {
+        unsafe { ffi::SoPlex_optimize(*self.inner) };
+    }
+
+    /// Returns statistics about the solve, see `SolveStatistics`.
+    pub fn statistics(&self) -> SolveStatistics {
+        let col_status: Vec<ColBasisStatus> = (0..self.num_cols())
+            .map(|col_id| self.col_basis_status(col_id))
+            .collect();
+        let row_status: Vec<RowBasisStatus> = (0..self.num_rows())
+            .map(|row_id| self.row_basis_status(row_id))
+            .collect();
+        let num_basic_cols = col_status
+            .iter()
+            .filter(|&&s| s == ColBasisStatus::Basic)
+            .count();
+        let num_basic_rows = row_status
+            .iter()
+            .filter(|&&s| s == RowBasisStatus::Basic)
+            .count();
+
+        let (primal_degeneracy, dual_degeneracy) = if self.status() == Status::Optimal {
+            let (primal, dual) = self.degeneracy(&col_status, &row_status);
+            (Some(primal), Some(dual))
+        } else {
+            (None, None)
+        };
+
+        SolveStatistics {
+            num_iterations: self.num_iterations(),
+            solving_time: self.solving_time(),
+            num_basic_cols,
+            num_basic_rows,
+            primal_degeneracy,
+            dual_degeneracy,
+        }
+    }
+
+    fn degeneracy(
+        &self,
+        col_status: &[ColBasisStatus],
+        row_status: &[RowBasisStatus],
+    ) -> (f64, f64) {
+        let primal = self.primal_solution();
+        let (lower, upper) = col_bounds(&self.inner);
+        let redcosts = self.reduced_costs();
+        let dual = self.dual_solution();
+        let infinity = self.params.get(RealParam::Infty);
+        let at_bound = |value: f64, lower: f64, upper: f64| {
+            let at = |bound: f64| {
+                bound.abs() < infinity && (value - bound).abs() <= ZERO_TOL * (1.0 + bound.abs())
+            };
+            at(lower) || at(upper)
+        };
+
+        let mut num_basic = 0;
+        let mut num_primal_degenerate = 0;
+        let mut num_nonbasic = 0;
+        let mut num_dual_degenerate = 0;
+
+        for (col_id, status) in col_status.iter().enumerate() {
+            if *status == ColBasisStatus::Basic {
+                num_basic += 1;
+                if at_bound(primal[col_id], lower[col_id], upper[col_id]) {
+                    num_primal_degenerate += 1;
+                }
+            } else {
+                num_nonbasic += 1;
+                if redcosts[col_id].abs() <= ZERO_TOL {
+                    num_dual_degenerate += 1;
+                }
+            }
+        }
+
+        let mut reader = RowReader::new(&self.inner);
+        for (row_id, status) in row_status.iter().enumerate() {
+            if *status == RowBasisStatus::Basic {
+                num_basic += 1;
+                let activity: f64 = reader
+                    .row(row_id)
+                    .map(|(col_id, coef)| coef * primal[col_id])
+                    .sum();
+                let (lhs, rhs) = row_range(&self.inner, row_id);
+                if at_bound(activity, lhs, rhs) {
+                    num_primal_degenerate += 1;
+                }
+            } else {
+                num_nonbasic += 1;
+                if dual[row_id].abs() <= ZERO_TOL {
+                    num_dual_degenerate += 1;
+                }
+            }
+        }
+
+        (
+            fraction(num_primal_degenerate, num_basic),
+            fraction(num_dual_degenerate, num_nonbasic),
+        )
+    }
+}
+
+fn fraction(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// A copy of the data of a model, used to build modified copies of it with `Model::load`.
+#[derive(Clone)]
+pub(crate) struct LpData {
+    /// The objective value of every column.
+    pub(crate) obj: Vec<f64>,
+    /// The lower and upper bound of every column.
+    pub(crate) col_bounds: Vec<(f64, f64)>,
+    /// The left- and right-hand side of every row.
+    pub(crate) row_ranges: Vec<(f64, f64)>,
+    /// The nonzero entries of every row.
+    pub(crate) rows: Vec<Vec<(ColId, f64)>>,
+    /// Bounds and sides with an absolute value of at least `infinity` are infinite.
+    pub(crate) infinity: f64,
+}
+
+impl LpData {
+    fn new(inner: &SoplexPtr, params: &Parameters) -> Self {
+        let num_cols = unsafe { ffi::SoPlex_numCols(**inner) } as usize;
+        let num_rows = unsafe { ffi::SoPlex_numRows(**inner) } as usize;
+        let mut obj = vec![0.0; num_cols];
+        unsafe { ffi::SoPlex_getObjReal(**inner, obj.as_mut_ptr(), num_cols as i32) };
+        let (lower, upper) = col_bounds(inner);
+        let mut reader = RowReader::new(inner);
+        let rows = (0..num_rows)
+            .map(|row_id| reader.row(row_id).collect())
+            .collect();
+        Self {
+            obj,
+            col_bounds: lower.into_iter().zip(upper).collect(),
+            row_ranges: (0..num_rows)
+                .map(|row_id| row_range(inner, row_id))
+                .collect(),
+            rows,
+            infinity: params.get(RealParam::Infty),
+        }
+    }
+
+    /// Returns whether a bound or side is finite.
+    pub(crate) fn is_finite(&self, value: f64) -> bool {
+        value.abs() < self.infinity
+    }
+}
+
+/// Returns the lower and upper bounds of all columns.
+fn col_bounds(inner: &SoplexPtr) -> (Vec<f64>, Vec<f64>) {
+    let num_cols = unsafe { ffi::SoPlex_numCols(**inner) };
+    let mut lower = vec![0.0; num_cols as usize];
+    let mut upper = vec![0.0; num_cols as usize];
+    unsafe {
+        ffi::SoPlex_getLowerReal(**inner, lower.as_mut_ptr(), num_cols);
+        ffi::SoPlex_getUpperReal(**inner, upper.as_mut_ptr(), num_cols);
+    }
+    (lower, upper)
+}
+
+/// Returns the left- and right-hand side of a row.
+fn row_range(inner: &SoplexPtr, row_id: RowId) -> (f64, f64) {
+    let mut lhs = 0.0;
+    let mut rhs = 0.0;
+    unsafe { ffi::SoPlex_getRowBoundsReal(**inner, row_id as i32, &mut lhs, &mut rhs) };
+    (lhs, rhs)
+}
+
+/// Reads the rows of the constraint matrix into buffers that are reused for every row.
+struct RowReader<'a> {
+    inner: &'a SoplexPtr,
+    indices: Vec<i64>,
+    coefs: Vec<f64>,
+}
+
+impl<'a> RowReader<'a> {
+    /// Allocates the buffers once, a row has at most as many nonzeros as there are columns.
+    fn new(inner: &'a SoplexPtr) -> Self {
+        let num_cols = unsafe { ffi::SoPlex_numCols(**inner) } as usize;
+        Self {
+            inner,
+            indices: vec![0; num_cols],
+            coefs: vec![0.0; num_cols],
+        }
+    }
+
+    /// Returns the nonzero entries of a row.
+    fn row(&mut self, row_id: RowId) -> impl Iterator<Item = (ColId, f64)> + '_ {
+        let mut nnonzeros = 0;
+        unsafe {
+            ffi::SoPlex_getRowVectorReal(
+                **self.inner,
+                row_id as i32,
+                &mut nnonzeros,
+                self.indices.as_mut_ptr(),
+                self.coefs.as_mut_ptr(),
+            );
+        }
+        let nnonzeros = nnonzeros as usize;
+        self.indices[..nnonzeros]
+            .iter()
+            .zip(&self.coefs[..nnonzeros])
+            .map(|(&col_id, &coef)| (col_id as ColId, coef))
+    }
 }
 
 impl From<SolvedModel> for Model {
     fn from(solved_model: SolvedModel) -> Self {
         Self {
             inner: solved_model.inner,
+            params: solved_model.params,
+            row_buffer: Vec::new(),
         }
     }
 }
@@ -542,7 +1171,7 @@ impl From<SolvedModel> for Model {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Algorithm;
+    use crate::{Algorithm, CoefRange};
 
     #[test]
     fn simple_problem() {
@@ -605,6 +1234,103 @@ mod tests {
         assert_eq!(num_iterations, 1);
     }
 
+    #[test]
+    fn solution() {
+        let lp = small_model().optimize();
+        let solution = lp.solution();
+        assert_eq!(solution.status, Status::Optimal);
+        assert_eq!(solution.obj_val, lp.obj_val());
+        assert_eq!(solution.primal, lp.primal_solution());
+        assert_eq!(solution.dual, lp.dual_solution());
+        assert_eq!(solution.reduced_costs, lp.reduced_costs());
+        assert_eq!(solution.col_basis.len(), 2);
+        assert_eq!(solution.row_basis, vec![RowBasisStatus::AtUpper]);
+    }
+
+    #[test]
+    fn statistics() {
+        let lp = small_model().optimize();
+        let stats = lp.statistics();
+        assert_eq!(stats.num_iterations, lp.num_iterations());
+        assert!(stats.solving_time >= 0.0);
+        assert_eq!(stats.num_basic_cols + stats.num_basic_rows, lp.num_rows());
+        let primal_degeneracy = stats.primal_degeneracy.unwrap();
+        assert!((0.0..=1.0).contains(&primal_degeneracy));
+        // both columns have the same objective, so the nonbasic column has a zero reduced cost
+        assert!(stats.dual_degeneracy.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn statistics_infinite_sides() {
+        // max x  s.t.  x - y <= 10, 0 <= x <= 4, 0 <= y <= 2, the row is basic and not at its side
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 4.0);
+        lp.add_col(vec![], 0.0, 0.0, 2.0);
+        lp.add_row(vec![1.0, -1.0], f64::NEG_INFINITY, 10.0);
+        lp.set_obj_sense(ObjSense::Maximize);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert_eq!(lp.row_basis_status(0), RowBasisStatus::Basic);
+        assert_eq!(lp.statistics().primal_degeneracy, Some(0.0));
+    }
+
+    #[test]
+    fn statistics_infeasible() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0], 2.0, 3.0);
+        let stats = lp.optimize().statistics();
+        assert_eq!(stats.primal_degeneracy, None);
+        assert_eq!(stats.dual_degeneracy, None);
+    }
+
+    #[test]
+    fn stats() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 5.0);
+        lp.add_col(vec![], 2e10, f64::NEG_INFINITY, f64::INFINITY);
+        lp.add_col(vec![], 0.0, 3.0, 3.0);
+        lp.add_row(vec![1.0, 1e-3], 1.0, 5.0);
+        lp.add_row(vec![0.0, 0.0, 4.0], f64::NEG_INFINITY, 12.0);
+        lp.add_row(vec![], 0.0, f64::INFINITY);
+        let stats = lp.stats();
+
+        assert_eq!(
+            (stats.num_rows, stats.num_cols, stats.num_nonzeros),
+            (3, 3, 3)
+        );
+        assert_eq!(
+            (
+                stats.col_bounds.free,
+                stats.col_bounds.fixed,
+                stats.col_bounds.boxed
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(
+            (
+                stats.row_sides.boxed,
+                stats.row_sides.upper,
+                stats.row_sides.lower
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!((stats.empty_rows, stats.empty_cols), (1, 0));
+        assert_eq!((stats.singleton_rows, stats.singleton_cols), (1, 3));
+        assert_eq!(
+            stats.matrix_range,
+            Some(CoefRange {
+                min: 1e-3,
+                max: 4.0
+            })
+        );
+        assert_eq!(stats.bounds_range.unwrap().max, 5.0);
+        assert_eq!(stats.warnings.len(), 2, "{:?}", stats.warnings);
+        assert!(stats
+            .to_string()
+            .contains("3 rows, 3 columns and 3 nonzeros"));
+    }
+
     #[test]
     fn set_int_param() {
         let mut lp = Model::new();
@@ -722,13 +1448,52 @@ mod tests {
         let mut lp = Model::new();
         lp.add_col(vec![], 1.0, 1.0, 1.0);
         lp.add_col(vec![], 1.0, 1.0, 1.0);
-        lp.set_obj_vals(&mut [2.0, 3.0]);
+        lp.set_obj_vals(&[2.0, 3.0]);
         let lp = lp.optimize();
         let result = lp.status();
         assert_eq!(result, Status::Optimal);
         assert!((lp.obj_val() - 5.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn set_bounds_and_ranges_all() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0, 0.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 1.0], 0.0, 1.0);
+        lp.set_obj_sense(ObjSense::Maximize);
+        lp.set_col_bounds_all(&[0.0, 0.0], &[5.0, 5.0]);
+        lp.set_row_ranges_all(&[0.0, 0.0], &[2.0, 3.0]);
+        assert_eq!(lp.lp_data().col_bounds, vec![(0.0, 5.0), (0.0, 5.0)]);
+        assert_eq!(lp.lp_data().row_ranges, vec![(0.0, 2.0), (0.0, 3.0)]);
+
+        lp.update_col_bounds(&[(1, 0.0, 1.5)]);
+        lp.update_row_ranges(&[(0, 0.0, 4.0)]);
+        assert_eq!(lp.lp_data().col_bounds, vec![(0.0, 5.0), (0.0, 1.5)]);
+        assert_eq!(lp.lp_data().row_ranges, vec![(0.0, 4.0), (0.0, 3.0)]);
+        let lp = lp.optimize();
+        assert!((lp.obj_val() - 5.5).abs() < 1e-6);
+
+        let mut lp = Model::from(lp);
+        lp.update_row_ranges(&[(1, 1.0, 2.0), (0, 1.0, 3.0)]);
+        assert_eq!(lp.lp_data().row_ranges, vec![(1.0, 3.0), (1.0, 2.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is out of range")]
+    fn update_row_ranges_out_of_range() {
+        let mut lp = small_model();
+        lp.update_row_ranges(&[(2, 0.0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 is out of range")]
+    fn update_col_bounds_out_of_range() {
+        let mut lp = small_model();
+        lp.update_col_bounds(&[(2, 0.0, 1.0)]);
+    }
+
     #[test]
     #[should_panic]
     fn read_non_existent_file_panic() {
@@ -743,6 +1508,183 @@ mod tests {
         lp.read_file("tests/data/simple.txt");
     }
 
+    #[test]
+    fn param_get_set() {
+        let mut lp = small_model();
+        assert!(!lp.param(BoolParam::Lifting));
+        assert_eq!(lp.param(IntParam::IterLimit), -1);
+
+        lp.set_param(BoolParam::Lifting, true).unwrap();
+        lp.set_param(IntParam::IterLimit, 0).unwrap();
+        lp.set_param(RealParam::FeasTol, 1e-9).unwrap();
+        assert!(lp.param(BoolParam::Lifting));
+        assert_eq!(lp.param(IntParam::IterLimit), 0);
+        assert_eq!(lp.param(RealParam::FeasTol), 1e-9);
+
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::AbortIter);
+        let lp = Model::from(lp);
+        assert_eq!(lp.param(IntParam::IterLimit), 0);
+    }
+
+    #[test]
+    fn set_param_invalid() {
+        let mut lp = Model::new();
+        let err = lp.set_param(RealParam::FeasTol, -1e-6).unwrap_err();
+        assert!(matches!(
+            err,
+            ParamError::OutOfRange {
+                name: "feastol",
+                ..
+            }
+        ));
+        assert_eq!(lp.param(RealParam::FeasTol), 1e-6);
+        assert!(lp.set_param(IntParam::IterLimit, -2).is_err());
+    }
+
+    #[test]
+    fn set_param_ignores_invalid() {
+        let mut lp = Model::new();
+        lp.set_real_param(RealParam::FeasTol, -1e-6);
+        assert_eq!(lp.param(RealParam::FeasTol), 1e-6);
+        lp.set_int_param(IntParam::DisplayFreq, 0);
+        assert_eq!(lp.param(IntParam::DisplayFreq), 200);
+        let code = IntParam::DisplayFreq.into();
+        assert_eq!(unsafe { ffi::SoPlex_getIntParam(*lp.inner, code) }, 200);
+    }
+
+    #[test]
+    fn parameters_snapshot() {
+        let mut lp = small_model();
+        lp.set_pricer_type(crate::Pricer::Devex);
+        lp.set_obj_sense(ObjSense::Minimize);
+        lp.set_real_param(RealParam::TimeLimit, 0.0);
+        let params = lp.parameters();
+        assert_eq!(params.pricer_type(), crate::Pricer::Devex);
+        assert_eq!(params.obj_sense(), ObjSense::Minimize);
+        assert_eq!(params.get(RealParam::TimeLimit), 0.0);
+
+        let mut other = small_model();
+        other.set_parameters(&params);
+        assert_eq!(other.parameters(), params);
+        assert_eq!(other.obj_sense(), ObjSense::Minimize);
+        let other = other.optimize();
+        assert_eq!(other.status(), Status::AbortTime);
+    }
+
+    #[test]
+    fn read_settings() {
+        let mut lp = small_model();
+        lp.read_settings("tests/data/simple.set").unwrap();
+        assert!(lp.param(BoolParam::Lifting));
+        assert_eq!(lp.param(IntParam::IterLimit), 0);
+        assert_eq!(lp.param(RealParam::FeasTol), 1e-9);
+        assert_eq!(lp.obj_sense(), ObjSense::Minimize);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::AbortIter);
+    }
+
+    #[test]
+    fn read_settings_error() {
+        let mut lp = Model::new();
+        assert!(matches!(
+            lp.read_settings("i_do_not_exist.set"),
+            Err(SettingsError::Io(_))
+        ));
+        assert!(matches!(
+            lp.read_settings("tests/data/simple.mps"),
+            Err(SettingsError::Syntax { line: 1, .. })
+        ));
+        assert_eq!(lp.parameters(), Parameters::new());
+    }
+
+    #[test]
+    fn write_settings() {
+        let path = std::env::temp_dir().join("soplex_rs_write_settings.set");
+        let path = path.to_str().unwrap();
+
+        let mut lp = Model::new();
+        lp.set_param(RealParam::FeasTol, 1e-9).unwrap();
+        lp.set_pricer_type(crate::Pricer::Devex);
+        lp.write_settings(path, true).unwrap();
+
+        let mut other = Model::new();
+        other.read_settings(path).unwrap();
+        assert_eq!(other.parameters(), lp.parameters());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn apply_preset() {
+        let mut lp = small_model();
+        lp.apply_preset(Preset::Fast);
+        let params = lp.parameters();
+        assert_eq!(params.pricer_type(), crate::Pricer::Devex);
+        assert_eq!(params.ratio_tester_type(), crate::RatioTester::Fast);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 5.0).abs() < 1e-6);
+
+        let mut lp = small_model();
+        lp.apply_preset(Preset::NumericallySafe);
+        assert_eq!(lp.param(RealParam::FeasTol), 1e-9);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 5.0).abs() < 1e-9);
+
+        let mut lp = Model::from(lp);
+        lp.apply_preset(Preset::WarmStartFriendly);
+        assert_eq!(lp.parameters().simplifier_type(), crate::Simplifier::Off);
+        lp.change_col_bounds(0, 0.0, 1.0);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn expressions() {
+        let mut lp = Model::new();
+        let x = lp.add_var(0.0, 0.0, 10.0);
+        let y = lp.add_var(0.0, 0.0, 10.0);
+        let c1 = lp.add_constr((x + y).ge(4.0));
+        let c2 = lp.add_constr((x - y + 1.0).le(0.0));
+        lp.add_constr((2.0 * y).between(0.0, 12.0));
+        lp.set_objective(&(3.0 * x + 2.0 * y + 1.0), ObjSense::Minimize);
+        assert_eq!((c1, c2), (0, 1));
+        assert_eq!(lp.num_rows(), 3);
+
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        // y <= 6 and x >= 4 - y, y >= x + 1, so x = 0, y = 4 is optimal
+        assert!((lp.obj_val() - 9.0).abs() < 1e-6);
+        let primal = lp.primal_solution();
+        assert!((primal[x.col_id()] - 0.0).abs() < 1e-6);
+        assert!((primal[y.col_id()] - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn constr_rows() {
+        let mut lp = Model::new();
+        let vars: Vec<Var> = (0..5).map(|_| lp.add_var(0.0, 0.0, 1.0)).collect();
+        lp.add_constr((vars[3] + 2.0 * vars[1] + vars[3]).le(1.0));
+        // the entries of the previous row must not leak into the next one
+        lp.add_constr((vars[0] - vars[0] + vars[2]).ge(0.0));
+        lp.add_constr(LinExpr::new().le(1.0));
+        assert_eq!(
+            lp.lp_data().rows,
+            vec![vec![(1, 2.0), (3, 2.0)], vec![(2, 1.0)], vec![]]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn constr_with_unknown_var_panic() {
+        let mut lp = Model::new();
+        let x = lp.add_var(1.0, 0.0, 1.0);
+        let mut other = Model::new();
+        other.add_constr((x + 1.0).le(2.0));
+    }
+
     #[test]
     fn obj_sense() {
         let lp = Model::new();
@@ -1,5 +1,6 @@
 /// Represents the boolean parameters for some LP solver.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoolParam {
     /// Should lifting be used to reduce range of nonzero matrix coefficients?
     Lifting = 0,
@@ -9,49 +10,50 @@ pub enum BoolParam {
     TestDualInf = 2,
     /// Should a rational factorization be performed after iterative refinement?
     RatFac = 3,
-    /// Should the decomposition based dual simplex be used to solve the LP?
-    /// Setting this to true forces the solve mode to SOLVEMODE_REAL and the basis representation to REPRESENTATION_ROW
-    UseDecompDualSimplex = 4,
-    /// Should the degeneracy be computed for each basis?
-    ComputeDegen = 5,
-    /// Should the dual of the complementary problem be used in the decomposition simplex?
-    UseCompDual = 6,
-    /// Should row and bound violations be computed explicitly in the update of reduced problem in the decomposition simplex
-    ExplicitViol = 7,
     /// Should cycling solutions be accepted during iterative refinement?
-    AcceptCycling = 8,
+    AcceptCycling = 4,
     /// Apply rational reconstruction after each iterative refinement?
-    RatRec = 9,
+    RatRec = 5,
     /// Round scaling factors for iterative refinement to powers of two?
-    PowerScaling = 10,
+    PowerScaling = 6,
     /// Continue iterative refinement with exact basic solution if not optimal?
-    RatFacJump = 11,
+    RatFacJump = 7,
     /// Use bound flipping also for row representation?
-    RowBoundFlips = 12,
+    RowBoundFlips = 8,
     /// Use persistent scaling?
-    PersistentScaling = 13,
+    PersistentScaling = 9,
     /// Perturb the entire problem or only the relevant bounds of a single pivot?
-    FullPerturbation = 14,
+    FullPerturbation = 10,
     /// Re-optimize the original problem to get a proof (ray) of infeasibility/unboundedness?
-    EnsureRay = 15,
+    EnsureRay = 11,
     /// Try to enforce that the optimal solution is a basic solution
-    ForceBasic = 16,
+    ForceBasic = 12,
     /// Enable presolver SingletonCols in PaPILO?
-    SimplifierSingletonCols = 17,
+    SimplifierSingletonCols = 13,
     /// Enable presolver ConstraintPropagation in PaPILO?
-    SimplifierConstraintPropagation = 18,
+    SimplifierConstraintPropagation = 14,
     /// Enable presolver ParallelRowDetection in PaPILO?
-    SimplifierParallelRowDetection = 19,
+    SimplifierParallelRowDetection = 15,
     /// Enable presolver ParallelColDetection in PaPILO?
-    SimplifierParallelColDetection = 20,
+    SimplifierParallelColDetection = 16,
     /// Enable presolver SingletonStuffing in PaPILO?
-    SimplifierSingletonStuffing = 21,
+    SimplifierSingletonStuffing = 17,
     /// Enable presolver DualFix in PaPILO?
-    SimplifierDualFix = 22,
+    SimplifierDualFix = 18,
     /// Enable presolver FixContinuous in PaPILO?
-    SimplifierFixContinuous = 23,
+    SimplifierFixContinuous = 19,
     /// Enable presolver DominatedCols in PaPILO?
-    SimplifierDominatedCols = 24,
+    SimplifierDominatedCols = 20,
+    /// Enable iterative refinement?
+    IterativeRefinement = 21,
+    /// Adapt tolerances to the multiprecision used?
+    AdaptTolsToMultiprecision = 22,
+    /// Enable precision boosting?
+    PrecisionBoosting = 23,
+    /// Start the boosted solver from the last basis instead of the slack basis?
+    BoostedWarmStart = 24,
+    /// Enable the recovery mechanism for when the solve fails?
+    RecoveryMechanism = 25,
 }
 
 pub(crate) const OBJSENSE_PARAM_ID: i32 = 0;
@@ -71,11 +73,11 @@ pub(crate) const CHECK_MODE_PARAM_ID: i32 = 18;
 pub(crate) const TIMER_PARAM_ID: i32 = 19;
 pub(crate) const HYPER_PRICING_PARAM_ID: i32 = 20;
 pub(crate) const SOLUTION_POLISHING_PARAM_ID: i32 = 23;
-pub(crate) const DECOMP_VERBOSITY_PARAM_ID: i32 = 27;
-pub(crate) const STAT_TIMER_PARAM_ID: i32 = 29;
+pub(crate) const STAT_TIMER_PARAM_ID: i32 = 25;
 
 /// Enum representing the objective sense for optimization.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjSense {
     /// Minimize the objective function.
     Minimize = -1,
@@ -85,6 +87,7 @@ pub enum ObjSense {
 
 /// Enum representing the type of representation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Representation {
     /// Automatically determine the representation type.
     Auto = 0,
@@ -96,6 +99,7 @@ pub enum Representation {
 
 /// Enum representing the type of algorithm used.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Algorithm {
     /// Primal algorithm.
     Primal = 0,
@@ -105,6 +109,7 @@ pub enum Algorithm {
 
 /// Enum representing the factor update type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FactorUpdateType {
     /// ETA update type.
     Eta = 0,
@@ -114,6 +119,7 @@ pub enum FactorUpdateType {
 
 /// Enum representing verbosity levels.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Verbosity {
     /// Only show errors.
     Error = 0,
@@ -131,6 +137,7 @@ pub enum Verbosity {
 
 /// Enum representing the simplifier type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Simplifier {
     /// Simplification is turned off.
     Off = 0,
@@ -144,6 +151,7 @@ pub enum Simplifier {
 
 /// Enum representing the scalar type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scalar {
     /// Scalar operation is turned off.
     Off = 0,
@@ -163,6 +171,7 @@ pub enum Scalar {
 
 /// Enum representing the starter type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Starter {
     /// Starter is turned off.
     Off = 0,
@@ -176,6 +185,7 @@ pub enum Starter {
 
 /// Enum representing the pricer type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Pricer {
     /// Automatically choose the pricer.
     Auto = 0,
@@ -193,6 +203,7 @@ pub enum Pricer {
 
 /// Enum representing the ratio tester type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RatioTester {
     /// Use textbook ratio test.
     Textbook = 0,
@@ -206,7 +217,7 @@ pub enum RatioTester {
 
 /// Enum representing the synchronization mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SyncMode {
     /// Only sync real values.
     Onlyreal = 0,
@@ -218,6 +229,7 @@ pub enum SyncMode {
 
 /// Enum representing the read mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReadMode {
     /// Read real values.
     Real = 0,
@@ -227,7 +239,7 @@ pub enum ReadMode {
 
 /// Enum representing the solve mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SolveMode {
     /// Solve with real values.
     Real = 0,
@@ -239,7 +251,7 @@ pub enum SolveMode {
 
 /// Enum representing the check mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CheckMode {
     /// Check real values.
     Real = 0,
@@ -251,7 +263,7 @@ pub enum CheckMode {
 
 /// Enum representing the timer type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Timer {
     /// Timer is turned off.
     Off = 0,
@@ -263,7 +275,7 @@ pub enum Timer {
 
 /// Enum representing the hyperpricing mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HyperPricing {
     /// Hyperpricing is turned off.
     Off = 0,
@@ -275,7 +287,7 @@ pub enum HyperPricing {
 
 /// Enum representing the solution polishing mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SolutionPolishing {
     /// Solution polishing is turned off.
     Off = 0,
@@ -285,8 +297,29 @@ pub enum SolutionPolishing {
     Fractionality = 2,
 }
 
+/// Named bundles of parameters for common use cases, applied with `Model::apply_preset`.
+///
+/// A preset only changes the parameters listed for it, all others keep their current value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Preset {
+    /// Favor speed over robustness: `Simplifier::Internal`, `Scalar::Biequi`, `Pricer::Devex`,
+    /// `RatioTester::Fast`, `Algorithm::Dual`, `SolveMode::Real`, `CheckMode::Real` and
+    /// feasibility and optimality tolerances of 1e-6.
+    Fast,
+    /// Favor robustness on badly scaled problems: `Simplifier::Internal`, `Scalar::Leastsq`, `Pricer::Steep`,
+    /// `RatioTester::Harris`, `Algorithm::Dual`, `SolveMode::Real`, `CheckMode::Auto` and
+    /// feasibility and optimality tolerances of 1e-9.
+    NumericallySafe,
+    /// Keep the basis usable between solves of a modified model: `Simplifier::Off`, `Starter::Off`,
+    /// `Algorithm::Dual`, `Representation::Auto` and `SolveMode::Real`. Presolving is switched off since
+    /// it transforms the problem and the basis of the previous solve could not be reused.
+    WarmStartFriendly,
+}
+
 /// Represents the integer parameters for some LP solver.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IntParam {
     /// Maximum number of updates without fresh factorization
     FactorUpdateMax = 4,
@@ -302,18 +335,17 @@ pub enum IntParam {
     RatFacMinStalls = 21,
     /// Maximum number of conjugate gradient iterations in least square scaling
     LeastSqMaxRounds = 22,
-    /// Number of iterations before the decomposition simplex initialization is terminated
-    DecompIterLimit = 24,
-    /// Maximum number of rows added in each iteration of the decomposition based simplex
-    DecompMaxAddedRows = 25,
-    /// Iteration frequency at which the decomposition solve output is displayed
-    DecompDisplayFreq = 26,
     /// Print condition number during the solve
-    PrintBasisMetric = 28,
+    PrintBasisMetric = 24,
+    /// Maximum number of digits for the multiprecision type
+    MultiprecisionLimit = 26,
+    /// Maximum number of simplex pivots after which the advanced and stable basis is stored
+    StoreBasisSimplexFreq = 27,
 }
 
 /// Represents the real number parameters for some LP solver.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RealParam {
     /// Primal feasibility tolerance
     FeasTol = 0,
@@ -367,6 +399,8 @@ pub enum RealParam {
     MinMarkowitz = 24,
     /// Minimal modification threshold to apply presolve reductions
     SimplifierModifyRowFac = 25,
+    /// Factor by which the precision of the floating-point solver is multiplied
+    PrecisionBoostingFactor = 26,
 }
 
 macro_rules! impl_from_int_param {
@@ -391,6 +425,97 @@ impl From<i32> for ObjSense {
     }
 }
 
+macro_rules! impl_from_i32 {
+    ($($param:ident { $($variant:ident),* }),*) => {
+        $(
+            impl From<i32> for $param {
+                fn from(value: i32) -> Self {
+                    match value {
+                        $(v if v == $param::$variant as i32 => $param::$variant,)*
+                        _ => panic!(concat!("Invalid value for ", stringify!($param), ": {}"), value),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_i32!(
+    Representation { Auto, Column, Row },
+    Algorithm { Primal, Dual },
+    FactorUpdateType { Eta, Ft },
+    Verbosity {
+        Error,
+        Warning,
+        Debug,
+        Normal,
+        High,
+        Full
+    },
+    Simplifier {
+        Off,
+        Internal,
+        Papilo,
+        Auto
+    },
+    Scalar {
+        Off,
+        Uniequi,
+        Biequi,
+        Ge01,
+        Ge08,
+        Leastsq,
+        Geoequi
+    },
+    Starter {
+        Off,
+        Weight,
+        Sum,
+        Vector
+    },
+    Pricer {
+        Auto,
+        Dantzig,
+        Parmult,
+        Devex,
+        Quicksteep,
+        Steep
+    },
+    RatioTester {
+        Textbook,
+        Harris,
+        Fast,
+        Boundflipping
+    },
+    SyncMode {
+        Onlyreal,
+        Auto,
+        Manual
+    },
+    ReadMode { Real, Rational },
+    SolveMode {
+        Real,
+        Auto,
+        Rational
+    },
+    CheckMode {
+        Real,
+        Auto,
+        Rational
+    },
+    Timer {
+        Off,
+        Cpu,
+        Wallclock
+    },
+    HyperPricing { Off, Auto, On },
+    SolutionPolishing {
+        Off,
+        Integrality,
+        Fractionality
+    }
+);
+
 impl_from_int_param!(
     BoolParam,
     IntParam,
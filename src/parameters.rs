@@ -0,0 +1,727 @@
+use crate::ffi;
+use crate::param::*;
+use std::ffi::c_void;
+use std::fmt;
+
+/// Number of boolean parameters.
+pub(crate) const NUM_BOOL_PARAMS: usize = 26;
+/// Number of integer parameters, including the ones holding an enum value.
+pub(crate) const NUM_INT_PARAMS: usize = 28;
+/// Number of real parameters.
+pub(crate) const NUM_REAL_PARAMS: usize = 27;
+
+/// Largest value SoPlex accepts for real parameters that are unbounded.
+const INFINITY: f64 = 1e100;
+
+/// Name and default value of every boolean parameter, indexed by parameter code.
+pub(crate) const BOOL_PARAMS: [(BoolParam, &str, bool); NUM_BOOL_PARAMS] = [
+    (BoolParam::Lifting, "lifting", false),
+    (BoolParam::EqTrans, "eqtrans", false),
+    (BoolParam::TestDualInf, "testdualinf", false),
+    (BoolParam::RatFac, "ratfac", true),
+    (BoolParam::AcceptCycling, "acceptcycling", false),
+    (BoolParam::RatRec, "ratrec", true),
+    (BoolParam::PowerScaling, "powerscaling", true),
+    (BoolParam::RatFacJump, "ratfacjump", false),
+    (BoolParam::RowBoundFlips, "rowboundflips", false),
+    (BoolParam::PersistentScaling, "persistentscaling", true),
+    (BoolParam::FullPerturbation, "fullperturbation", false),
+    (BoolParam::EnsureRay, "ensureray", false),
+    (BoolParam::ForceBasic, "forcebasic", false),
+    (
+        BoolParam::SimplifierSingletonCols,
+        "simplifier_enable_singletoncols",
+        true,
+    ),
+    (
+        BoolParam::SimplifierConstraintPropagation,
+        "simplifier_enable_propagation",
+        true,
+    ),
+    (
+        BoolParam::SimplifierParallelRowDetection,
+        "simplifier_enable_parallelrows",
+        true,
+    ),
+    (
+        BoolParam::SimplifierParallelColDetection,
+        "simplifier_enable_parallelcols",
+        true,
+    ),
+    (
+        BoolParam::SimplifierSingletonStuffing,
+        "simplifier_enable_stuffing",
+        true,
+    ),
+    (
+        BoolParam::SimplifierDualFix,
+        "simplifier_enable_dualfix",
+        true,
+    ),
+    (
+        BoolParam::SimplifierFixContinuous,
+        "simplifier_enable_fixcontinuous",
+        true,
+    ),
+    (
+        BoolParam::SimplifierDominatedCols,
+        "simplifier_enable_domcol",
+        true,
+    ),
+    (BoolParam::IterativeRefinement, "iterative_refinement", true),
+    (
+        BoolParam::AdaptTolsToMultiprecision,
+        "adapt_tols_to_multiprecision",
+        false,
+    ),
+    (BoolParam::PrecisionBoosting, "precision_boosting", false),
+    (BoolParam::BoostedWarmStart, "boosted_warm_start", true),
+    (BoolParam::RecoveryMechanism, "recovery_mechanism", false),
+];
+
+/// Name, default value, lower and upper bound of every integer parameter, indexed by parameter code.
+pub(crate) const INT_PARAMS: [(&str, i32, i32, i32); NUM_INT_PARAMS] = [
+    ("objsense", 1, -1, 1),
+    ("representation", 0, 0, 2),
+    ("algorithm", 1, 0, 1),
+    ("factor_update_type", 1, 0, 1),
+    ("factor_update_max", 0, 0, i32::MAX),
+    ("iterlimit", -1, -1, i32::MAX),
+    ("reflimit", -1, -1, i32::MAX),
+    ("stallreflimit", -1, -1, i32::MAX),
+    ("displayfreq", 200, 1, i32::MAX),
+    ("verbosity", 3, 0, 5),
+    ("simplifier", 3, 0, 3),
+    ("scaler", 2, 0, 6),
+    ("starter", 0, 0, 3),
+    ("pricer", 0, 0, 5),
+    ("ratiotester", 3, 0, 3),
+    ("syncmode", 0, 0, 2),
+    ("readmode", 0, 0, 1),
+    ("solvemode", 1, 0, 2),
+    ("checkmode", 1, 0, 2),
+    ("timer", 1, 0, 2),
+    ("hyperpricing", 1, 0, 2),
+    ("ratfac_minstalls", 2, 0, i32::MAX),
+    ("leastsq_maxrounds", 50, 0, i32::MAX),
+    ("solution_polishing", 0, 0, 2),
+    ("printbasismetric", -1, -1, 3),
+    ("stattimer", 1, 0, 2),
+    ("multiprecision_limit", 300, 50, i32::MAX),
+    ("storeBasisSimplexFreq", 10000, 1, i32::MAX),
+];
+
+/// Name, default value, lower and upper bound of every real parameter, indexed by parameter code.
+pub(crate) const REAL_PARAMS: [(RealParam, &str, f64, f64, f64); NUM_REAL_PARAMS] = [
+    (RealParam::FeasTol, "feastol", 1e-6, 0.0, 1.0),
+    (RealParam::OptTol, "opttol", 1e-6, 0.0, 1.0),
+    (RealParam::EpsilonZero, "epsilon_zero", 1e-16, 0.0, 1.0),
+    (
+        RealParam::EpsilonFactorization,
+        "epsilon_factorization",
+        1e-20,
+        0.0,
+        1.0,
+    ),
+    (RealParam::EpsilonUpdate, "epsilon_update", 1e-16, 0.0, 1.0),
+    (RealParam::EpsilonPivot, "epsilon_pivot", 1e-10, 0.0, 1.0),
+    (RealParam::Infty, "infty", INFINITY, 1e10, INFINITY),
+    (RealParam::TimeLimit, "timelimit", INFINITY, 0.0, INFINITY),
+    (
+        RealParam::ObjLimitLower,
+        "objlimit_lower",
+        -INFINITY,
+        -INFINITY,
+        INFINITY,
+    ),
+    (
+        RealParam::ObjLimitUpper,
+        "objlimit_upper",
+        INFINITY,
+        -INFINITY,
+        INFINITY,
+    ),
+    (RealParam::FpFeasTol, "fpfeastol", 1e-9, 0.0, 1.0),
+    (RealParam::FpOptTol, "fpopttol", 1e-9, 0.0, 1.0),
+    (RealParam::MaxScaleIncr, "maxscaleincr", 1e25, 1.0, INFINITY),
+    (RealParam::LiftMinVal, "liftminval", 0.000976562, 0.0, 0.1),
+    (RealParam::LiftMaxVal, "liftmaxval", 1024.0, 10.0, INFINITY),
+    (
+        RealParam::SparsityThreshold,
+        "sparsity_threshold",
+        0.6,
+        0.0,
+        1.0,
+    ),
+    (
+        RealParam::RepresentationSwitch,
+        "representation_switch",
+        1.2,
+        0.0,
+        INFINITY,
+    ),
+    (RealParam::RatRecFreq, "ratrec_freq", 1.2, 1.0, INFINITY),
+    (RealParam::MinRed, "minred", 1e-4, 0.0, 1.0),
+    (
+        RealParam::RefacBasisNnz,
+        "refac_basis_nnz",
+        10.0,
+        1.0,
+        100.0,
+    ),
+    (
+        RealParam::RefacUpdateFill,
+        "refac_update_fill",
+        5.0,
+        1.0,
+        100.0,
+    ),
+    (
+        RealParam::RefacMemFactor,
+        "refac_mem_factor",
+        1.5,
+        1.0,
+        10.0,
+    ),
+    (
+        RealParam::LeastSqAcrcy,
+        "leastsq_acrcy",
+        1000.0,
+        1.0,
+        INFINITY,
+    ),
+    (RealParam::ObjOffset, "obj_offset", 0.0, -INFINITY, INFINITY),
+    (
+        RealParam::MinMarkowitz,
+        "min_markowitz",
+        0.01,
+        0.0001,
+        0.9999,
+    ),
+    (
+        RealParam::SimplifierModifyRowFac,
+        "simplifier_modifyrowfac",
+        1.0,
+        0.0,
+        1.0,
+    ),
+    (
+        RealParam::PrecisionBoostingFactor,
+        "precision_boosting_factor",
+        1.5,
+        1.0,
+        10.0,
+    ),
+];
+
+/// Error returned when a parameter value is rejected.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParamError {
+    /// The value lies outside of the range SoPlex accepts for the parameter.
+    OutOfRange {
+        /// Name of the parameter.
+        name: &'static str,
+        /// The rejected value.
+        value: f64,
+        /// Smallest accepted value.
+        min: f64,
+        /// Largest accepted value.
+        max: f64,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "value {} for parameter {} is outside of the valid range [{}, {}]",
+                value, name, min, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+pub(crate) mod private {
+    use super::Parameters;
+    use std::ffi::c_void;
+
+    pub trait Sealed {}
+
+    /// Storage and FFI access for the value type of a parameter.
+    pub trait Value: Copy {
+        fn load(params: &Parameters, code: usize) -> Self;
+        fn store(self, params: &mut Parameters, code: usize);
+        fn apply(self, soplex: *mut c_void, code: i32);
+    }
+}
+
+impl private::Value for bool {
+    fn load(params: &Parameters, code: usize) -> Self {
+        params.bools[code]
+    }
+
+    fn store(self, params: &mut Parameters, code: usize) {
+        params.bools[code] = self;
+    }
+
+    fn apply(self, soplex: *mut c_void, code: i32) {
+        unsafe { ffi::SoPlex_setBoolParam(soplex, code, self as i32) };
+    }
+}
+
+impl private::Value for i32 {
+    fn load(params: &Parameters, code: usize) -> Self {
+        params.ints[code]
+    }
+
+    fn store(self, params: &mut Parameters, code: usize) {
+        params.ints[code] = self;
+    }
+
+    fn apply(self, soplex: *mut c_void, code: i32) {
+        unsafe { ffi::SoPlex_setIntParam(soplex, code, self) };
+    }
+}
+
+impl private::Value for f64 {
+    fn load(params: &Parameters, code: usize) -> Self {
+        params.reals[code]
+    }
+
+    fn store(self, params: &mut Parameters, code: usize) {
+        params.reals[code] = self;
+    }
+
+    fn apply(self, soplex: *mut c_void, code: i32) {
+        unsafe { ffi::SoPlex_setRealParam(soplex, code, self) };
+    }
+}
+
+/// A SoPlex parameter that can be read and written through `Model::param` and `Model::set_param`.
+///
+/// Implemented by `BoolParam`, `IntParam` and `RealParam`.
+pub trait Param: Copy + Into<i32> + private::Sealed {
+    /// Type of the parameter value.
+    type Value: private::Value + fmt::Debug + PartialEq;
+
+    /// Returns the name of the parameter as used in SoPlex settings files.
+    fn name(self) -> &'static str;
+
+    /// Returns the default value of the parameter.
+    fn default_value(self) -> Self::Value;
+
+    /// Checks whether `value` is a valid value for the parameter.
+    fn validate(self, value: Self::Value) -> Result<(), ParamError>;
+}
+
+impl private::Sealed for BoolParam {}
+
+impl Param for BoolParam {
+    type Value = bool;
+
+    fn name(self) -> &'static str {
+        BOOL_PARAMS[self as usize].1
+    }
+
+    fn default_value(self) -> bool {
+        BOOL_PARAMS[self as usize].2
+    }
+
+    fn validate(self, _value: bool) -> Result<(), ParamError> {
+        Ok(())
+    }
+}
+
+impl private::Sealed for IntParam {}
+
+impl Param for IntParam {
+    type Value = i32;
+
+    fn name(self) -> &'static str {
+        INT_PARAMS[self as usize].0
+    }
+
+    fn default_value(self) -> i32 {
+        INT_PARAMS[self as usize].1
+    }
+
+    fn validate(self, value: i32) -> Result<(), ParamError> {
+        validate_int(self as usize, value)
+    }
+}
+
+/// Checks an integer parameter value given by parameter code, including the ones holding an enum value.
+pub(crate) fn validate_int(code: usize, value: i32) -> Result<(), ParamError> {
+    let (name, _, min, max) = INT_PARAMS[code];
+    if value < min || value > max {
+        return Err(ParamError::OutOfRange {
+            name,
+            value: value as f64,
+            min: min as f64,
+            max: max as f64,
+        });
+    }
+    Ok(())
+}
+
+impl private::Sealed for RealParam {}
+
+impl Param for RealParam {
+    type Value = f64;
+
+    fn name(self) -> &'static str {
+        REAL_PARAMS[self as usize].1
+    }
+
+    fn default_value(self) -> f64 {
+        REAL_PARAMS[self as usize].2
+    }
+
+    fn validate(self, value: f64) -> Result<(), ParamError> {
+        let (_, name, _, min, max) = REAL_PARAMS[self as usize];
+        // written so that NaN is rejected as well
+        if !(value >= min && value <= max) {
+            return Err(ParamError::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A snapshot of all SoPlex parameters.
+///
+/// Obtained from `Model::parameters` and applied with `Model::set_parameters`.
+///
+/// With the `serde` feature, parameters are serialized as maps from the SoPlex parameter name to its value,
+/// grouped into `bool`, `int` and `real`. Parameters missing when deserializing keep their default value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ParametersRepr", try_from = "ParametersRepr")
+)]
+pub struct Parameters {
+    pub(crate) bools: [bool; NUM_BOOL_PARAMS],
+    pub(crate) ints: [i32; NUM_INT_PARAMS],
+    pub(crate) reals: [f64; NUM_REAL_PARAMS],
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parameters {
+    /// Creates a parameter set with the SoPlex default values.
+    pub fn new() -> Self {
+        Self {
+            bools: BOOL_PARAMS.map(|(_, _, default)| default),
+            ints: INT_PARAMS.map(|(_, default, _, _)| default),
+            reals: REAL_PARAMS.map(|(_, _, default, _, _)| default),
+        }
+    }
+
+    /// Returns the value of a parameter.
+    ///
+    /// # Arguments
+    /// * `param` - The `BoolParam`, `IntParam` or `RealParam` to read.
+    pub fn get<P: Param>(&self, param: P) -> P::Value {
+        private::Value::load(self, param.into() as usize)
+    }
+
+    /// Sets the value of a parameter.
+    ///
+    /// # Arguments
+    /// * `param` - The `BoolParam`, `IntParam` or `RealParam` to set.
+    /// * `value` - The value of the parameter.
+    ///
+    /// # Errors
+    /// if the value is outside of the valid range of the parameter.
+    pub fn set<P: Param>(&mut self, param: P, value: P::Value) -> Result<(), ParamError> {
+        param.validate(value)?;
+        private::Value::store(value, self, param.into() as usize);
+        Ok(())
+    }
+}
+
+/// Serialized form of `Parameters`, keyed by the names used in SoPlex settings files.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ParametersRepr {
+    #[serde(default, rename = "bool")]
+    bools: std::collections::BTreeMap<String, bool>,
+    #[serde(default, rename = "int")]
+    ints: std::collections::BTreeMap<String, i32>,
+    #[serde(default, rename = "real")]
+    reals: std::collections::BTreeMap<String, f64>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Parameters> for ParametersRepr {
+    fn from(params: Parameters) -> Self {
+        Self {
+            bools: BOOL_PARAMS
+                .iter()
+                .zip(params.bools)
+                .map(|((_, name, _), value)| (name.to_string(), value))
+                .collect(),
+            ints: INT_PARAMS
+                .iter()
+                .zip(params.ints)
+                .map(|((name, _, _, _), value)| (name.to_string(), value))
+                .collect(),
+            reals: REAL_PARAMS
+                .iter()
+                .zip(params.reals)
+                .map(|((_, name, _, _, _), value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ParametersRepr> for Parameters {
+    type Error = String;
+
+    fn try_from(repr: ParametersRepr) -> Result<Self, Self::Error> {
+        let mut params = Parameters::new();
+        for (name, value) in repr.bools {
+            let (param, _, _) = BOOL_PARAMS
+                .iter()
+                .find(|(_, n, _)| *n == name)
+                .ok_or_else(|| format!("unknown parameter bool:{}", name))?;
+            params.set(*param, value).map_err(|err| err.to_string())?;
+        }
+        for (name, value) in repr.ints {
+            let code = INT_PARAMS
+                .iter()
+                .position(|(n, _, _, _)| *n == name)
+                .ok_or_else(|| format!("unknown parameter int:{}", name))?;
+            validate_int(code, value).map_err(|err| err.to_string())?;
+            params.ints[code] = value;
+        }
+        for (name, value) in repr.reals {
+            let (param, _, _, _, _) = REAL_PARAMS
+                .iter()
+                .find(|(_, n, _, _, _)| *n == name)
+                .ok_or_else(|| format!("unknown parameter real:{}", name))?;
+            params.set(*param, value).map_err(|err| err.to_string())?;
+        }
+        Ok(params)
+    }
+}
+
+macro_rules! impl_enum_params {
+    ($($getter:ident, $setter:ident, $ty:ty, $code:expr, $doc:literal;)*) => {
+        impl Parameters {
+            $(
+                #[doc = concat!("Returns the ", $doc, ".")]
+                pub fn $getter(&self) -> $ty {
+                    self.ints[$code as usize].into()
+                }
+
+                #[doc = concat!("Sets the ", $doc, ".")]
+                pub fn $setter(&mut self, value: $ty) {
+                    self.ints[$code as usize] = value.into();
+                }
+            )*
+        }
+    };
+}
+
+impl_enum_params!(
+    obj_sense, set_obj_sense, ObjSense, OBJSENSE_PARAM_ID, "objective sense";
+    representation, set_representation, Representation, REPR_PARAM_ID, "basis representation";
+    algorithm, set_algorithm, Algorithm, ALGORITHM_PARAM_ID, "simplex algorithm";
+    factor_update_type, set_factor_update_type, FactorUpdateType, FACTOR_UPDATE_TYPE_PARAM_ID, "factor update type";
+    verbosity, set_verbosity, Verbosity, VERBOSITY_PARAM_ID, "verbosity level";
+    simplifier_type, set_simplifier_type, Simplifier, SIMPLIFIER_PARAM_ID, "simplifier type";
+    scalar_type, set_scalar_type, Scalar, SCALAR_PARAM_ID, "scalar type";
+    starter_type, set_starter_type, Starter, STARTER_PARAM_ID, "starter type";
+    pricer_type, set_pricer_type, Pricer, PRICER_PARAM_ID, "pricer type";
+    ratio_tester_type, set_ratio_tester_type, RatioTester, RATIO_TESTER_PARAM_ID, "ratio tester type";
+    sync_mode, set_sync_mode, SyncMode, SYNC_MODE_PARAM_ID, "sync mode";
+    read_mode, set_read_mode, ReadMode, READ_MODE_PARAM_ID, "read mode";
+    solve_mode, set_solve_mode, SolveMode, SOLVE_MODE_PARAM_ID, "solve mode";
+    check_mode, set_check_mode, CheckMode, CHECK_MODE_PARAM_ID, "check mode";
+    timer_mode, set_timer_mode, Timer, TIMER_PARAM_ID, "timer mode";
+    hyper_pricing, set_hyper_pricing, HyperPricing, HYPER_PRICING_PARAM_ID, "hyper pricing mode";
+    solution_polishing, set_solution_polishing, SolutionPolishing, SOLUTION_POLISHING_PARAM_ID, "solution polishing type";
+    stat_timer, set_stat_timer, Timer, STAT_TIMER_PARAM_ID, "statistics timer";
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soplex_ptr::SoplexPtr;
+
+    /// Returns the `(type, name, range, value)` of every parameter in a settings file written by
+    /// SoPlex with all parameters, where `range` is the comment line above the parameter.
+    fn soplex_defaults() -> Vec<(String, String, String, String)> {
+        let content = std::fs::read_to_string("tests/data/soplex_defaults.set").unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        lines
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                let (ty, rest) = line.split_once(':')?;
+                let (name, value) = rest.split_once(" = ")?;
+                let range = lines[idx - 1].trim_start_matches("# range ");
+                Some((ty.into(), name.into(), range.into(), value.into()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tables_match_soplex_settings() {
+        let defaults = soplex_defaults();
+        let of_type = |ty: &str| {
+            defaults
+                .iter()
+                .filter(|(t, _, _, _)| t == ty)
+                .map(|(_, name, range, value)| (name.as_str(), range.as_str(), value.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        let bools = of_type("bool");
+        assert_eq!(bools.len(), NUM_BOOL_PARAMS);
+        for ((_, name, default), (soplex_name, _, value)) in BOOL_PARAMS.iter().zip(bools) {
+            assert_eq!((*name, default.to_string().as_str()), (soplex_name, value));
+        }
+
+        let ints = of_type("int");
+        assert_eq!(ints.len(), NUM_INT_PARAMS);
+        for ((name, default, min, max), (soplex_name, range, value)) in INT_PARAMS.iter().zip(ints)
+        {
+            let expected = format!("[{},{}], default {}", min, max, default);
+            assert_eq!((*name, expected.as_str()), (soplex_name, range));
+            assert_eq!(value.parse::<i32>().unwrap(), *default);
+        }
+
+        let reals = of_type("real");
+        assert_eq!(reals.len(), NUM_REAL_PARAMS);
+        for ((_, name, default, min, max), (soplex_name, range, value)) in
+            REAL_PARAMS.iter().zip(reals)
+        {
+            assert_eq!(*name, soplex_name);
+            let (bounds, soplex_default) = range.split_once("], default ").unwrap();
+            let (soplex_min, soplex_max) = bounds.trim_start_matches('[').split_once(',').unwrap();
+            // SoPlex writes 9 significant digits
+            let close = |a: f64, b: &str| {
+                let b: f64 = b.parse().unwrap();
+                (a - b).abs() <= 1e-8 * a.abs().max(b.abs())
+            };
+            assert!(close(*default, soplex_default), "default of {}", name);
+            assert!(close(*default, value), "value of {}", name);
+            assert!(
+                close(*min, soplex_min) && close(*max, soplex_max),
+                "range of {}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn int_defaults_match_soplex() {
+        let soplex = SoplexPtr::new();
+        for (code, (name, default, _, _)) in INT_PARAMS.iter().enumerate() {
+            let value = unsafe { ffi::SoPlex_getIntParam(*soplex, code as i32) };
+            assert_eq!(value, *default, "default of {}", name);
+        }
+    }
+
+    #[test]
+    fn tables_match_codes() {
+        for (code, (param, _, _)) in BOOL_PARAMS.iter().enumerate() {
+            assert_eq!(*param as usize, code);
+        }
+        for (code, (param, _, _, _, _)) in REAL_PARAMS.iter().enumerate() {
+            assert_eq!(*param as usize, code);
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        let params = Parameters::new();
+        assert!(params.get(BoolParam::RatFac));
+        assert_eq!(params.get(IntParam::IterLimit), -1);
+        assert_eq!(params.get(RealParam::FeasTol), 1e-6);
+        assert_eq!(params.obj_sense(), ObjSense::Maximize);
+        assert_eq!(params.algorithm(), Algorithm::Dual);
+        assert_eq!(params.ratio_tester_type(), RatioTester::Boundflipping);
+    }
+
+    #[test]
+    fn set_and_validate() {
+        let mut params = Parameters::new();
+        params.set(RealParam::FeasTol, 1e-9).unwrap();
+        assert_eq!(params.get(RealParam::FeasTol), 1e-9);
+
+        assert!(params.set(RealParam::FeasTol, -1.0).is_err());
+        assert!(params.set(RealParam::FeasTol, f64::NAN).is_err());
+        assert!(params.set(IntParam::DisplayFreq, 0).is_err());
+        assert_eq!(params.get(RealParam::FeasTol), 1e-9);
+        assert_eq!(params.get(IntParam::DisplayFreq), 200);
+
+        params.set_pricer_type(Pricer::Devex);
+        assert_eq!(params.pricer_type(), Pricer::Devex);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut params = Parameters::new();
+        params.set(BoolParam::RatFac, false).unwrap();
+        params.set(IntParam::IterLimit, 1000).unwrap();
+        params.set(RealParam::FeasTol, 1e-9).unwrap();
+        params.set_pricer_type(Pricer::Devex);
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["bool"]["ratfac"], false);
+        assert_eq!(json["int"]["iterlimit"], 1000);
+        assert_eq!(json["int"]["pricer"], i32::from(Pricer::Devex));
+        assert_eq!(json["real"]["feastol"], 1e-9);
+
+        let read: Parameters = serde_json::from_value(json).unwrap();
+        assert_eq!(read, params);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_missing_and_invalid() {
+        let read: Parameters = serde_json::from_str(r#"{"int": {"iterlimit": 10}}"#).unwrap();
+        assert_eq!(read.get(IntParam::IterLimit), 10);
+        assert_eq!(read.get(RealParam::FeasTol), 1e-6);
+
+        let err = serde_json::from_str::<Parameters>(r#"{"int": {"no_such_param": 1}}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("unknown parameter int:no_such_param"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<Parameters>(r#"{"real": {"feastol": -1.0}}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("feastol"), "{}", err);
+        let err = serde_json::from_str::<Parameters>(r#"{"int": {"pricer": 42}}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("pricer"), "{}", err);
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{Solution, SolvedModel, Status};
+
+/// Relative tolerance to decide whether two objective values coincide.
+const OBJ_TOL: f64 = 1e-7;
+
+/// An interval of the parameter `t` on which one basis stays optimal, see
+/// `SolvedModel::parametric_objective` and `SolvedModel::parametric_rhs`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParametricInterval {
+    /// Start of the interval, a breakpoint unless it is the start of the range.
+    pub t_start: f64,
+    /// End of the interval, a breakpoint unless it is the end of the range.
+    pub t_end: f64,
+    /// Optimal objective value at `t_start`.
+    pub obj_start: f64,
+    /// Optimal objective value at `t_end`.
+    pub obj_end: f64,
+    /// Slope of the optimal objective value on the interval.
+    pub slope: f64,
+    /// The solution that is optimal on the interval, taken at some `t` in the interval.
+    pub solution: Solution,
+}
+
+/// An optimal solution for one value of `t`.
+struct Sample {
+    t: f64,
+    obj: f64,
+    slope: f64,
+    solution: Solution,
+}
+
+impl Sample {
+    /// Objective value at `t` of the optimal solution of the sample.
+    fn line(&self, t: f64) -> f64 {
+        self.obj + (t - self.t) * self.slope
+    }
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= OBJ_TOL * (1.0 + a.abs().max(b.abs()))
+}
+
+impl SolvedModel {
+    /// Computes the optimal objective value of the model with objective `c + t * d` for all `t` in
+    /// `t_range`, where `c` is the current objective.
+    ///
+    /// The optimal value is piecewise linear in `t`. Its breakpoints are found by intersecting
+    /// the objective lines of optimal solutions (Eisner-Severance), every solve is warm started
+    /// from the basis of the previous one. The objective `c` is restored and the model optimized
+    /// again afterwards.
+    ///
+    /// # Arguments
+    /// * `d` - The cost direction, one entry per column.
+    /// * `t_range` - The finite range `(t_min, t_max)` of the parameter.
+    ///
+    /// # Returns
+    /// The intervals between consecutive breakpoints in increasing order of `t`, or the status of the
+    /// first solve that did not end optimal.
+    ///
+    /// # Panics
+    /// if `d` does not have one entry per column or `t_range` is not a finite, non-empty range.
+    pub fn parametric_objective(
+        &mut self,
+        d: &[f64],
+        t_range: (f64, f64),
+    ) -> Result<Vec<ParametricInterval>, Status> {
+        assert_eq!(d.len(), self.num_cols(), "d must have one entry per column");
+        let (t_min, t_max) = t_range;
+        assert!(
+            t_min.is_finite() && t_max.is_finite() && t_min <= t_max,
+            "t_range must be a finite range"
+        );
+
+        let c = self.obj_vals();
+        let mut sample = |model: &mut SolvedModel, t: f64| {
+            let objvals: Vec<f64> = c.iter().zip(d).map(|(c, d)| c + t * d).collect();
+            model.change_obj_vals(&objvals);
+            model.sample(t, |solution| {
+                solution.primal.iter().zip(d).map(|(x, d)| x * d).sum()
+            })
+        };
+        let result = self.walk(t_min, t_max, &mut sample);
+        self.change_obj_vals(&c);
+        self.reoptimize();
+        result
+    }
+
+    /// Computes the optimal objective value of the model with both sides of every row moved by
+    /// `t * direction` for all `t` in `[0, t_max]`, infinite sides stay infinite.
+    ///
+    /// Breakpoints of the piecewise linear optimal value are found as in `parametric_objective`,
+    /// the slopes follow from the dual solution. Moving the sides keeps the basis dual feasible, so
+    /// with SoPlex's default dual simplex every step is a dual simplex warm start. If the model
+    /// becomes infeasible before `t_max`, the largest feasible `t` is located by bisection and the
+    /// last interval ends there. The row ranges are restored and the model optimized again afterwards.
+    ///
+    /// # Arguments
+    /// * `direction` - The direction of the row sides, one entry per row.
+    /// * `t_max` - The finite, nonnegative end of the parameter range.
+    ///
+    /// # Returns
+    /// The intervals between consecutive breakpoints in increasing order of `t`, or the status of the
+    /// first solve that did not end optimal for reasons other than infeasibility beyond `t = 0`.
+    ///
+    /// # Panics
+    /// if `direction` does not have one entry per row or `t_max` is negative or not finite.
+    pub fn parametric_rhs(
+        &mut self,
+        direction: &[f64],
+        t_max: f64,
+    ) -> Result<Vec<ParametricInterval>, Status> {
+        assert_eq!(
+            direction.len(),
+            self.num_rows(),
+            "direction must have one entry per row"
+        );
+        assert!(
+            t_max.is_finite() && t_max >= 0.0,
+            "t_max must be finite and nonnegative"
+        );
+
+        let ranges = self.row_ranges();
+        let mut sample = |model: &mut SolvedModel, t: f64| {
+            for (row_id, (&(lhs, rhs), &dir)) in ranges.iter().zip(direction).enumerate() {
+                if dir != 0.0 {
+                    model.change_row_range(row_id, lhs + t * dir, rhs + t * dir);
+                }
+            }
+            model.sample(t, |solution| {
+                solution
+                    .dual
+                    .iter()
+                    .zip(direction)
+                    .map(|(y, dir)| y * dir)
+                    .sum()
+            })
+        };
+        let result = self
+            .feasible_end(t_max, &mut sample)
+            .and_then(|t_end| self.walk(0.0, t_end, &mut sample));
+        for (row_id, &(lhs, rhs)) in ranges.iter().enumerate() {
+            self.change_row_range(row_id, lhs, rhs);
+        }
+        self.reoptimize();
+        result
+    }
+
+    /// Returns `t_max` if the model is feasible there, otherwise the largest feasible `t` in `[0, t_max]`.
+    fn feasible_end(
+        &mut self,
+        t_max: f64,
+        sample: &mut impl FnMut(&mut SolvedModel, f64) -> Result<Sample, Status>,
+    ) -> Result<f64, Status> {
+        match sample(self, t_max) {
+            Err(Status::Infeasible) => {}
+            result => return result.map(|_| t_max),
+        }
+        sample(self, 0.0)?;
+        let (mut feasible, mut infeasible) = (0.0, t_max);
+        while infeasible - feasible > OBJ_TOL * (1.0 + infeasible.abs()) {
+            let t = 0.5 * (feasible + infeasible);
+            match sample(self, t) {
+                Ok(_) => feasible = t,
+                Err(Status::Infeasible) => infeasible = t,
+                Err(status) => return Err(status),
+            }
+        }
+        Ok(feasible)
+    }
+
+    /// Walks the breakpoints of the optimal value function on `[t_min, t_max]`.
+    fn walk(
+        &mut self,
+        t_min: f64,
+        t_max: f64,
+        sample: &mut impl FnMut(&mut SolvedModel, f64) -> Result<Sample, Status>,
+    ) -> Result<Vec<ParametricInterval>, Status> {
+        // samples whose lines form the optimal value function, with the start of their interval
+        let mut pieces = vec![(t_min, sample(self, t_min)?)];
+        let mut pending = vec![sample(self, t_max)?];
+
+        while let Some(right) = pending.pop() {
+            let (_, left) = pieces.last().expect("pieces is never empty");
+            if close(left.line(right.t), right.obj) {
+                // same line, nothing in between
+                continue;
+            }
+            let t = (right.obj - left.obj + left.t * left.slope - right.t * right.slope)
+                / (left.slope - right.slope);
+            if !(t > left.t && t < right.t) {
+                pieces.push((right.t, right));
+                continue;
+            }
+            let mid = sample(self, t)?;
+            if close(mid.obj, left.line(t)) {
+                pieces.push((t, right));
+            } else {
+                pending.push(right);
+                pending.push(mid);
+            }
+        }
+
+        let ends: Vec<f64> = pieces
+            .iter()
+            .skip(1)
+            .map(|&(t, _)| t)
+            .chain([t_max])
+            .collect();
+        Ok(pieces
+            .into_iter()
+            .zip(ends)
+            .map(|((t_start, sample), t_end)| ParametricInterval {
+                t_start,
+                t_end,
+                obj_start: sample.line(t_start),
+                obj_end: sample.line(t_end),
+                slope: sample.slope,
+                solution: sample.solution,
+            })
+            .collect())
+    }
+
+    /// Optimizes the changed model and returns the solution at `t`.
+    fn sample(&mut self, t: f64, slope: impl FnOnce(&Solution) -> f64) -> Result<Sample, Status> {
+        self.reoptimize();
+        let status = self.status();
+        if status != Status::Optimal {
+            return Err(status);
+        }
+        let solution = self.solution();
+        Ok(Sample {
+            t,
+            obj: solution.obj_val,
+            slope: slope(&solution),
+            solution,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Model, ObjSense, Status};
+
+    #[test]
+    fn parametric_objective() {
+        // min (1 + t) x + (1 - t) y  s.t.  x + y >= 2, 0 <= x, y <= 3
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 3.0);
+        lp.add_col(vec![], 1.0, 0.0, 3.0);
+        lp.add_row(vec![1.0, 1.0], 2.0, f64::INFINITY);
+        lp.set_obj_sense(ObjSense::Minimize);
+        let mut lp = lp.optimize();
+
+        let intervals = lp.parametric_objective(&[1.0, -1.0], (-2.0, 2.0)).unwrap();
+        // x = 3 for t < -1, x = 2 up to 0, y = 2 up to 1 and y = 3 afterwards
+        let breakpoints: Vec<f64> = intervals.iter().map(|i| i.t_start).collect();
+        assert_eq!(intervals.len(), 4, "{:?}", breakpoints);
+        let expected = [-2.0, -1.0, 0.0, 1.0];
+        for (interval, t) in intervals.iter().zip(expected) {
+            assert!((interval.t_start - t).abs() < 1e-6, "{:?}", breakpoints);
+            assert_eq!(interval.solution.status, Status::Optimal);
+        }
+        assert!((intervals[3].t_end - 2.0).abs() < 1e-6);
+        assert!((intervals[0].obj_start + 3.0).abs() < 1e-6);
+        assert!((intervals[1].slope - 2.0).abs() < 1e-6);
+
+        // the original objective is restored
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parametric_rhs() {
+        // min x + 2y  s.t.  x + y >= 1 + t, 0 <= x <= 2, 0 <= y <= 2
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 2.0);
+        lp.add_col(vec![], 2.0, 0.0, 2.0);
+        lp.add_row(vec![1.0, 1.0], 1.0, f64::INFINITY);
+        lp.set_obj_sense(ObjSense::Minimize);
+        let mut lp = lp.optimize();
+
+        // x covers the row up to t = 1, y up to t = 3, infeasible afterwards
+        let intervals = lp.parametric_rhs(&[1.0], 5.0).unwrap();
+        assert_eq!(intervals.len(), 2);
+        assert!((intervals[0].slope - 1.0).abs() < 1e-6);
+        assert!((intervals[1].t_start - 1.0).abs() < 1e-6);
+        assert!((intervals[1].slope - 2.0).abs() < 1e-6);
+        assert!((intervals[1].t_end - 3.0).abs() < 1e-5);
+        assert!((intervals[1].obj_end - 6.0).abs() < 1e-4);
+
+        // the original row ranges are restored
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::elastic::{Elastic, Origin, Side};
+use crate::{ColId, Model, RowId, Status};
+use std::collections::HashMap;
+
+/// Violations below this value are not reported.
+const VIOLATION_TOL: f64 = 1e-9;
+
+/// The constraints that may be violated in `Model::feasibility_relaxation`, with the cost per unit of violation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelaxationWeights {
+    /// Weights of the rows, both sides of a row may be violated.
+    pub rows: HashMap<RowId, f64>,
+    /// Weights of the column bounds, both bounds of a column may be violated.
+    pub col_bounds: HashMap<ColId, f64>,
+}
+
+/// Result of `Model::feasibility_relaxation`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Relaxation {
+    /// Status of the solve of the relaxation, `Infeasible` if the constraints that may not be violated
+    /// are infeasible on their own.
+    pub status: Status,
+    /// The weighted sum of the violations.
+    pub total_violation: f64,
+    /// Values of the columns in the repaired solution, indexed by `ColId`.
+    pub primal: Vec<f64>,
+    /// The violated rows, with the violated side and the amount of the violation.
+    pub rows: Vec<(RowId, Side, f64)>,
+    /// The violated column bounds, with the violated side and the amount of the violation.
+    pub col_bounds: Vec<(ColId, Side, f64)>,
+}
+
+impl Model {
+    /// Finds a solution that violates the selected rows and column bounds as little as possible.
+    ///
+    /// Every finite side of a selected row or bound gets an elastic slack column in a copy of the
+    /// model, the copy minimizes the weighted sum of the slacks. The model itself is not changed.
+    ///
+    /// # Arguments
+    /// * `weights` - The rows and bounds that may be violated, with their cost per unit of violation.
+    ///
+    /// # Returns
+    /// The violations mapped back to the rows and columns of the model. The solution fields are
+    /// empty unless `status` is `Status::Optimal`.
+    pub fn feasibility_relaxation(&self, weights: &RelaxationWeights) -> Relaxation {
+        let elastic = Elastic::new(self.lp_data(), |origin, _| match origin {
+            Origin::Row(row_id) => weights.rows.get(&row_id).copied(),
+            Origin::Bounds(col_id) => weights.col_bounds.get(&col_id).copied(),
+        });
+        let num_cols = elastic.num_cols;
+        let solved = elastic.model.optimize();
+        let status = solved.status();
+        if status != Status::Optimal {
+            return Relaxation {
+                status,
+                total_violation: f64::NAN,
+                primal: Vec::new(),
+                rows: Vec::new(),
+                col_bounds: Vec::new(),
+            };
+        }
+
+        let mut primal = solved.primal_solution();
+        let slack_values = primal.split_off(num_cols);
+        let mut relaxation = Relaxation {
+            status,
+            total_violation: solved.obj_val(),
+            primal,
+            rows: Vec::new(),
+            col_bounds: Vec::new(),
+        };
+        for (&(row_id, side), value) in elastic.slacks.iter().zip(slack_values) {
+            if value <= VIOLATION_TOL {
+                continue;
+            }
+            match elastic.origins[row_id] {
+                Origin::Row(row_id) => relaxation.rows.push((row_id, side, value)),
+                Origin::Bounds(col_id) => relaxation.col_bounds.push((col_id, side, value)),
+            }
+        }
+        relaxation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feasibility_relaxation() {
+        // demand x + y >= 5 with capacities x <= 1, y <= 1 and a budget x + y <= 4
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0, 1.0], 5.0, f64::INFINITY);
+        lp.add_row(vec![1.0, 1.0], f64::NEG_INFINITY, 4.0);
+
+        // extending a capacity is cheaper than missing demand
+        let weights = RelaxationWeights {
+            rows: HashMap::from([(0, 10.0)]),
+            col_bounds: HashMap::from([(0, 2.0), (1, 3.0)]),
+        };
+        let relaxation = lp.feasibility_relaxation(&weights);
+        assert_eq!(relaxation.status, Status::Optimal);
+        // the budget row is hard, so 1 unit of demand stays unmet
+        assert_eq!(relaxation.rows.len(), 1);
+        assert_eq!(
+            (relaxation.rows[0].0, relaxation.rows[0].1),
+            (0, Side::Lower)
+        );
+        assert!((relaxation.rows[0].2 - 1.0).abs() < 1e-6);
+        assert_eq!(relaxation.col_bounds.len(), 1);
+        let (col_id, side, amount) = relaxation.col_bounds[0];
+        assert_eq!((col_id, side), (0, Side::Upper));
+        assert!((amount - 2.0).abs() < 1e-6);
+        assert!((relaxation.total_violation - 14.0).abs() < 1e-6);
+        assert!((relaxation.primal[0] - 3.0).abs() < 1e-6);
+        assert_eq!(lp.num_cols(), 2);
+    }
+
+    #[test]
+    fn feasibility_relaxation_hard_infeasible() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0], 2.0, 3.0);
+        let relaxation = lp.feasibility_relaxation(&RelaxationWeights::default());
+        assert_eq!(relaxation.status, Status::Infeasible);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{ColBasisStatus, ColId, Model, RowBasisStatus, RowId, SolvedModel};
+
+/// Solution values of a column passed to the predicate of `SolvedModel::remove_cols_where`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct ColInfo {
+    /// The primal value of the column.
+    pub value: f64,
+    /// The reduced cost of the column.
+    pub reduced_cost: f64,
+    /// The basis status of the column.
+    pub basis_status: ColBasisStatus,
+}
+
+/// Solution values of a row passed to the predicate of `SolvedModel::remove_rows_where`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct RowInfo {
+    /// The activity of the row in the primal solution.
+    pub activity: f64,
+    /// The dual value of the row.
+    pub dual: f64,
+    /// The basis status of the row.
+    pub basis_status: RowBasisStatus,
+}
+
+impl SolvedModel {
+    /// Removes the columns for which the predicate holds, e.g. nonbasic columns at their lower
+    /// bound with a large reduced cost.
+    ///
+    /// The predicate looks at the solution, which removing columns invalidates, so the solved model
+    /// is consumed and an unsolved one returned.
+    ///
+    /// # Arguments
+    /// * `predicate` - Gets the id and the solution values of every column, returns whether to remove it.
+    ///
+    /// # Returns
+    /// The model without the columns and, for every old column id, its new id or `None` if it was removed.
+    pub fn remove_cols_where(
+        self,
+        mut predicate: impl FnMut(ColId, &ColInfo) -> bool,
+    ) -> (Model, Vec<Option<ColId>>) {
+        let primal = self.primal_solution();
+        let reduced_costs = self.reduced_costs();
+        let removed: Vec<ColId> = (0..self.num_cols())
+            .filter(|&col_id| {
+                let info = ColInfo {
+                    value: primal[col_id],
+                    reduced_cost: reduced_costs[col_id],
+                    basis_status: self.col_basis_status(col_id),
+                };
+                predicate(col_id, &info)
+            })
+            .collect();
+
+        let num_cols = self.num_cols();
+        let mut model = Model::from(self);
+        let remap = remove(num_cols, &removed, |col_id| model.remove_col(col_id));
+        (model, remap)
+    }
+
+    /// Removes the rows for which the predicate holds, e.g. basic rows far from both sides.
+    ///
+    /// The predicate looks at the solution, which removing rows invalidates, so the solved model is
+    /// consumed and an unsolved one returned.
+    ///
+    /// # Arguments
+    /// * `predicate` - Gets the id and the solution values of every row, returns whether to remove it.
+    ///
+    /// # Returns
+    /// The model without the rows and, for every old row id, its new id or `None` if it was removed.
+    pub fn remove_rows_where(
+        self,
+        mut predicate: impl FnMut(RowId, &RowInfo) -> bool,
+    ) -> (Model, Vec<Option<RowId>>) {
+        let activities = self.row_activities();
+        let duals = self.dual_solution();
+        let removed: Vec<RowId> = (0..self.num_rows())
+            .filter(|&row_id| {
+                let info = RowInfo {
+                    activity: activities[row_id],
+                    dual: duals[row_id],
+                    basis_status: self.row_basis_status(row_id),
+                };
+                predicate(row_id, &info)
+            })
+            .collect();
+
+        let num_rows = self.num_rows();
+        let mut model = Model::from(self);
+        let remap = remove(num_rows, &removed, |row_id| model.remove_row(row_id));
+        (model, remap)
+    }
+}
+
+/// Removes the given ids in descending order and returns the new id of every old one.
+///
+/// Removing an id moves the last one into its place, so every id still sits at its old position
+/// when it is removed.
+fn remove(len: usize, removed: &[usize], mut remove: impl FnMut(usize)) -> Vec<Option<usize>> {
+    let mut order: Vec<usize> = (0..len).collect();
+    for &id in removed.iter().rev() {
+        remove(id);
+        order.swap_remove(id);
+    }
+    let mut remap = vec![None; len];
+    for (new_id, &old_id) in order.iter().enumerate() {
+        remap[old_id] = Some(new_id);
+    }
+    remap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ObjSense;
+
+    #[test]
+    fn remove_cols_where() {
+        // min x0 + 5 x1 + x2 + 7 x3  s.t.  x0 + x1 + x2 + x3 >= 2,  0 <= x <= 1
+        let mut lp = Model::new();
+        for obj in [1.0, 5.0, 1.0, 7.0] {
+            lp.add_col(vec![], obj, 0.0, 1.0);
+        }
+        lp.add_row(vec![1.0; 4], 2.0, f64::INFINITY);
+        lp.set_obj_sense(ObjSense::Minimize);
+        let lp = lp.optimize();
+
+        let (model, remap) = lp.remove_cols_where(|_, info| {
+            info.basis_status == ColBasisStatus::AtLower && info.reduced_cost > 2.0
+        });
+        assert_eq!(model.num_cols(), 2);
+        // x2 moved into the place of x1
+        assert_eq!(remap, vec![Some(0), None, Some(1), None]);
+        assert!((model.optimize().obj_val() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn remove_rows_where() {
+        // max x + y  s.t.  x <= 10,  x + y <= 2,  x + y <= 10,  y <= 1,  0 <= x, y <= 1
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0], f64::NEG_INFINITY, 10.0);
+        lp.add_row(vec![1.0, 1.0], f64::NEG_INFINITY, 2.0);
+        lp.add_row(vec![1.0, 1.0], f64::NEG_INFINITY, 10.0);
+        lp.add_row(vec![0.0, 1.0], f64::NEG_INFINITY, 1.0);
+        lp.set_obj_sense(ObjSense::Maximize);
+        let lp = lp.optimize();
+
+        let rhs = [10.0, 2.0, 10.0, 1.0];
+        let (model, remap) = lp.remove_rows_where(|row_id, info| info.activity < rhs[row_id] - 5.0);
+        assert_eq!(model.num_rows(), 2);
+        // y <= 1 moved into the place of x <= 10
+        assert_eq!(remap, vec![None, Some(1), None, Some(0)]);
+        assert!((model.optimize().obj_val() - 2.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::parameters::{validate_int, BOOL_PARAMS, INT_PARAMS, REAL_PARAMS};
+use crate::{ParamError, Parameters};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// Error returned when reading a SoPlex settings file fails.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SettingsError {
+    /// The settings file could not be read.
+    Io(std::io::Error),
+    /// A line does not have the form `type:name = value`.
+    Syntax {
+        /// Line number, starting at 1.
+        line: usize,
+        /// Description of the problem.
+        message: String,
+    },
+    /// The parameter type or name is not known. Such lines are skipped and reported instead of
+    /// failing the whole file, see `Parameters::apply_settings`.
+    UnknownParam {
+        /// Line number, starting at 1.
+        line: usize,
+        /// The parameter as written in the file, e.g. `real:feastol`.
+        name: String,
+    },
+    /// The value can not be parsed or is outside of the range of the parameter.
+    InvalidValue {
+        /// Line number, starting at 1.
+        line: usize,
+        /// The value as written in the file.
+        value: String,
+        /// The range violation, if the value could be parsed.
+        source: Option<ParamError>,
+    },
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Io(err) => write!(f, "could not read settings file: {}", err),
+            SettingsError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            SettingsError::UnknownParam { line, name } => {
+                write!(f, "line {}: unknown parameter {}", line, name)
+            }
+            SettingsError::InvalidValue {
+                line,
+                value,
+                source: Some(err),
+            } => write!(f, "line {}: invalid value {}: {}", line, value, err),
+            SettingsError::InvalidValue {
+                line,
+                value,
+                source: None,
+            } => write!(f, "line {}: invalid value {}", line, value),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SettingsError::Io(err) => Some(err),
+            SettingsError::InvalidValue {
+                source: Some(err), ..
+            } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SettingsError {
+    fn from(err: std::io::Error) -> Self {
+        SettingsError::Io(err)
+    }
+}
+
+impl Parameters {
+    /// Applies the parameters given in SoPlex settings format on top of the current values.
+    ///
+    /// Every non-empty line has the form `type:name = value`, e.g. `real:feastol = 1e-9`,
+    /// everything after a `#` is a comment.
+    ///
+    /// # Returns
+    /// The lines with unknown parameters as `SettingsError::UnknownParam`, they are skipped. Files
+    /// written by SoPlex contain `uint:random_seed`, which is not part of `Parameters`, and files of
+    /// other SoPlex versions may contain parameters the linked one does not have.
+    ///
+    /// # Errors
+    /// if a line can not be parsed or holds an invalid value. Lines before the offending one have
+    /// been applied then.
+    pub fn apply_settings(&mut self, settings: &str) -> Result<Vec<SettingsError>, SettingsError> {
+        let mut skipped = Vec::new();
+        for (idx, line) in settings.lines().enumerate() {
+            match self.apply_settings_line(idx + 1, line) {
+                Err(err @ SettingsError::UnknownParam { .. }) => skipped.push(err),
+                result => result?,
+            }
+        }
+        Ok(skipped)
+    }
+
+    fn apply_settings_line(&mut self, line: usize, text: &str) -> Result<(), SettingsError> {
+        let text = text.split('#').next().unwrap_or_default().trim();
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let syntax_error = |message: &str| SettingsError::Syntax {
+            line,
+            message: message.to_string(),
+        };
+        let (param, value) = text
+            .split_once('=')
+            .ok_or_else(|| syntax_error("expected '=' after parameter name"))?;
+        let (param_type, name) = param
+            .split_once(':')
+            .ok_or_else(|| syntax_error("expected ':' between parameter type and name"))?;
+        let (param_type, name, value) = (param_type.trim(), name.trim(), value.trim());
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(syntax_error("expected a single parameter value"));
+        }
+
+        let unknown = || SettingsError::UnknownParam {
+            line,
+            name: format!("{}:{}", param_type, name),
+        };
+        let invalid = |source| SettingsError::InvalidValue {
+            line,
+            value: value.to_string(),
+            source,
+        };
+
+        match param_type {
+            "bool" => {
+                let (param, _, _) = BOOL_PARAMS
+                    .iter()
+                    .find(|(_, n, _)| *n == name)
+                    .ok_or_else(unknown)?;
+                let value = match value.to_ascii_lowercase().as_str() {
+                    "true" | "t" | "1" => true,
+                    "false" | "f" | "0" => false,
+                    _ => return Err(invalid(None)),
+                };
+                self.set(*param, value).map_err(|err| invalid(Some(err)))
+            }
+            "int" => {
+                let code = INT_PARAMS
+                    .iter()
+                    .position(|(n, _, _, _)| *n == name)
+                    .ok_or_else(unknown)?;
+                let value = value.parse().map_err(|_| invalid(None))?;
+                validate_int(code, value).map_err(|err| invalid(Some(err)))?;
+                self.ints[code] = value;
+                Ok(())
+            }
+            "real" => {
+                let (param, _, _, _, _) = REAL_PARAMS
+                    .iter()
+                    .find(|(_, n, _, _, _)| *n == name)
+                    .ok_or_else(unknown)?;
+                let value = value.parse().map_err(|_| invalid(None))?;
+                self.set(*param, value).map_err(|err| invalid(Some(err)))
+            }
+            _ => Err(unknown()),
+        }
+    }
+
+    /// Serializes the parameters in SoPlex settings format.
+    ///
+    /// # Arguments
+    /// * `only_changed` - If true, only parameters that differ from their default value are written.
+    pub fn to_settings_string(&self, only_changed: bool) -> String {
+        let mut out = String::from("# SoPlex parameter settings\n");
+        for (param, name, default) in BOOL_PARAMS {
+            let value = self.get(param);
+            if !only_changed || value != default {
+                let _ = writeln!(out, "\n# range {{true, false}}, default {}", default);
+                let _ = writeln!(out, "bool:{} = {}", name, value);
+            }
+        }
+        for (code, (name, default, min, max)) in INT_PARAMS.into_iter().enumerate() {
+            let value = self.ints[code];
+            if !only_changed || value != default {
+                let _ = writeln!(out, "\n# range [{},{}], default {}", min, max, default);
+                let _ = writeln!(out, "int:{} = {}", name, value);
+            }
+        }
+        for (param, name, default, min, max) in REAL_PARAMS {
+            let value = self.get(param);
+            if !only_changed || value != default {
+                let _ = writeln!(
+                    out,
+                    "\n# range [{:?},{:?}], default {:?}",
+                    min, max, default
+                );
+                let _ = writeln!(out, "real:{} = {:?}", name, value);
+            }
+        }
+        out
+    }
+}
+
+impl FromStr for Parameters {
+    type Err = SettingsError;
+
+    /// Parses parameters in SoPlex settings format, parameters that are not mentioned keep their default value.
+    /// Unknown parameters are skipped, use `apply_settings` to get them.
+    fn from_str(settings: &str) -> Result<Self, Self::Err> {
+        let mut params = Parameters::new();
+        params.apply_settings(settings)?;
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soplex_ptr::SoplexPtr;
+    use crate::{BoolParam, IntParam, ObjSense, Pricer, RealParam};
+    use std::ffi::CString;
+
+    #[test]
+    fn parse() {
+        let params: Parameters = "# tuned settings\n\
+                                  bool:lifting = true\n\
+                                  int : pricer=3 # devex\n\
+                                  \n\
+                                  int:iterlimit = 1000\n\
+                                  real:feastol = 1e-9\n"
+            .parse()
+            .unwrap();
+        assert!(params.get(BoolParam::Lifting));
+        assert_eq!(params.pricer_type(), Pricer::Devex);
+        assert_eq!(params.get(IntParam::IterLimit), 1000);
+        assert_eq!(params.get(RealParam::FeasTol), 1e-9);
+        assert_eq!(params.get(RealParam::OptTol), 1e-6);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "bool:lifting true".parse::<Parameters>().unwrap_err();
+        assert!(matches!(err, SettingsError::Syntax { line: 1, .. }));
+
+        let err = "bool:lifting = maybe".parse::<Parameters>().unwrap_err();
+        assert!(matches!(
+            err,
+            SettingsError::InvalidValue { source: None, .. }
+        ));
+
+        let err = "real:feastol = -1".parse::<Parameters>().unwrap_err();
+        assert!(matches!(
+            err,
+            SettingsError::InvalidValue {
+                source: Some(ParamError::OutOfRange { .. }),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn skip_unknown() {
+        let mut params = Parameters::new();
+        let skipped = params
+            .apply_settings("\nreal:feastoll = 1e-9\nreal:feastol = 1e-9\nfloat:feastol = 1")
+            .unwrap();
+        assert_eq!(params.get(RealParam::FeasTol), 1e-9);
+        let skipped: Vec<_> = skipped
+            .into_iter()
+            .map(|err| match err {
+                SettingsError::UnknownParam { line, name } => (line, name),
+                err => panic!("unexpected {}", err),
+            })
+            .collect();
+        assert_eq!(
+            skipped,
+            vec![
+                (2, "real:feastoll".to_string()),
+                (4, "float:feastol".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_soplex_written() {
+        // both files were written by `SoPlex::saveSettingsFile` of the linked SoPlex
+        let mut params = Parameters::new();
+        let defaults = std::fs::read_to_string("tests/data/soplex_defaults.set").unwrap();
+        let skipped = params.apply_settings(&defaults).unwrap();
+        assert_eq!(params, Parameters::new());
+        assert!(matches!(
+            skipped.as_slice(),
+            [SettingsError::UnknownParam { name, .. }] if name == "uint:random_seed"
+        ));
+
+        let changed = std::fs::read_to_string("tests/data/soplex_changed.set").unwrap();
+        let params: Parameters = changed.parse().unwrap();
+        assert!(params.get(BoolParam::EnsureRay));
+        assert_eq!(params.obj_sense(), ObjSense::Minimize);
+        assert_eq!(params.get(IntParam::IterLimit), 1000);
+        assert_eq!(params.get(RealParam::FeasTol), 1e-9);
+    }
+
+    #[test]
+    fn soplex_reads_written() {
+        let mut params = Parameters::new();
+        params.set(BoolParam::EnsureRay, true).unwrap();
+        params.set(IntParam::IterLimit, 1000).unwrap();
+        params.set(RealParam::FeasTol, 1e-9).unwrap();
+        params.set_pricer_type(Pricer::Devex);
+        params.set_obj_sense(ObjSense::Minimize);
+        let path = std::env::temp_dir().join("soplex_rs_soplex_reads_written.set");
+        std::fs::write(&path, params.to_settings_string(false)).unwrap();
+
+        // SoPlex skips lines it can not parse, so the values are compared after reading
+        let soplex = SoplexPtr::new();
+        let filename = CString::new(path.to_str().unwrap()).unwrap();
+        let read = unsafe { crate::ffi::SoPlex_readSettingsFile(*soplex, filename.as_ptr()) };
+        std::fs::remove_file(&path).unwrap();
+        assert_ne!(read, 0);
+        for (code, &value) in params.ints.iter().enumerate() {
+            let read = unsafe { crate::ffi::SoPlex_getIntParam(*soplex, code as i32) };
+            assert_eq!(read, value, "int:{}", INT_PARAMS[code].0);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut params = Parameters::new();
+        params.set(BoolParam::Lifting, true).unwrap();
+        params.set(RealParam::FeasTol, 1e-9).unwrap();
+        params.set(RealParam::TimeLimit, 12.5).unwrap();
+        params.set_pricer_type(Pricer::Steep);
+
+        let full = params.to_settings_string(false);
+        assert_eq!(full.parse::<Parameters>().unwrap(), params);
+
+        let changed = params.to_settings_string(true);
+        assert_eq!(changed.parse::<Parameters>().unwrap(), params);
+        assert_eq!(
+            changed.lines().filter(|l| l.contains('=')).count(),
+            4,
+            "{}",
+            changed
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{ColBasisStatus, RowBasisStatus, SolvedModel, Status};
+
+/// A snapshot of the solution of a `SolvedModel`.
+///
+/// Unlike `SolvedModel`, it does not hold on to the solver and can be stored or, with the `serde` feature,
+/// serialized.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    /// Status of the solver.
+    pub status: Status,
+    /// Objective value.
+    pub obj_val: f64,
+    /// Primal solution, indexed by `ColId`.
+    pub primal: Vec<f64>,
+    /// Dual solution, indexed by `RowId`.
+    pub dual: Vec<f64>,
+    /// Reduced costs, indexed by `ColId`.
+    pub reduced_costs: Vec<f64>,
+    /// Basis status of the columns, indexed by `ColId`.
+    pub col_basis: Vec<ColBasisStatus>,
+    /// Basis status of the rows, indexed by `RowId`.
+    pub row_basis: Vec<RowBasisStatus>,
+}
+
+impl From<&SolvedModel> for Solution {
+    fn from(model: &SolvedModel) -> Self {
+        Self {
+            status: model.status(),
+            obj_val: model.obj_val(),
+            primal: model.primal_solution(),
+            dual: model.dual_solution(),
+            reduced_costs: model.reduced_costs(),
+            col_basis: (0..model.num_cols())
+                .map(|col_id| model.col_basis_status(col_id))
+                .collect(),
+            row_basis: (0..model.num_rows())
+                .map(|row_id| model.row_basis_status(row_id))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::Model;
+
+    #[test]
+    fn serde_round_trip() {
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 3.0);
+        lp.add_col(vec![], 2.0, 0.0, 10.0);
+        lp.add_row(vec![1.0, 1.0], 1.0, 4.0);
+        let solution = Solution::from(&lp.optimize());
+        assert_eq!(solution.status, Status::Optimal);
+
+        let json = serde_json::to_string(&solution).unwrap();
+        let read: Solution = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, solution);
+    }
+}
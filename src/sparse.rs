@@ -0,0 +1,152 @@
+use crate::Model;
+
+#[cfg(feature = "sprs")]
+impl Model {
+    /// Builds a model from a `sprs` constraint matrix, in CSR or CSC storage.
+    ///
+    /// # Arguments
+    /// * `matrix` - The constraint matrix, one row per constraint and one column per variable.
+    /// * `obj` - The objective value of every column.
+    /// * `col_bounds` - The lower and upper bound of every column.
+    /// * `row_ranges` - The left- and right-hand side of every row.
+    ///
+    /// The objective sense keeps its default, set it with `Model::set_obj_sense`.
+    ///
+    /// # Panics
+    /// if the lengths of `obj`, `col_bounds` and `row_ranges` do not match the shape of `matrix`.
+    pub fn from_sparse(
+        matrix: &sprs::CsMat<f64>,
+        obj: &[f64],
+        col_bounds: &[(f64, f64)],
+        row_ranges: &[(f64, f64)],
+    ) -> Model {
+        check_shape(matrix.rows(), matrix.cols(), obj, col_bounds, row_ranges);
+        let vectors = matrix.outer_iterator().map(|vector| {
+            vector
+                .iter()
+                .map(|(i, &value)| (i, value))
+                .collect::<Vec<_>>()
+        });
+        Model::load(obj, col_bounds, row_ranges, matrix.is_csr(), vectors)
+    }
+
+    /// Returns the constraint matrix in CSR storage.
+    pub fn constraint_matrix(&self) -> sprs::CsMat<f64> {
+        let mut triplets = sprs::TriMat::new((self.num_rows(), self.num_cols()));
+        for (row_id, col_id, value) in self.matrix_entries() {
+            triplets.add_triplet(row_id, col_id, value);
+        }
+        triplets.to_csr()
+    }
+}
+
+#[cfg(feature = "nalgebra-sparse")]
+impl Model {
+    /// Builds a model from a `nalgebra_sparse` constraint matrix in CSC storage.
+    ///
+    /// # Arguments
+    /// * `matrix` - The constraint matrix, one row per constraint and one column per variable.
+    /// * `obj` - The objective value of every column.
+    /// * `col_bounds` - The lower and upper bound of every column.
+    /// * `row_ranges` - The left- and right-hand side of every row.
+    ///
+    /// The objective sense keeps its default, set it with `Model::set_obj_sense`.
+    ///
+    /// # Panics
+    /// if the lengths of `obj`, `col_bounds` and `row_ranges` do not match the shape of `matrix`.
+    pub fn from_csc(
+        matrix: &nalgebra_sparse::CscMatrix<f64>,
+        obj: &[f64],
+        col_bounds: &[(f64, f64)],
+        row_ranges: &[(f64, f64)],
+    ) -> Model {
+        check_shape(matrix.nrows(), matrix.ncols(), obj, col_bounds, row_ranges);
+        let vectors = matrix.col_iter().map(|col| {
+            col.row_indices()
+                .iter()
+                .copied()
+                .zip(col.values().iter().copied())
+                .collect::<Vec<_>>()
+        });
+        Model::load(obj, col_bounds, row_ranges, false, vectors)
+    }
+
+    /// Returns the constraint matrix in CSC storage.
+    pub fn constraint_matrix_csc(&self) -> nalgebra_sparse::CscMatrix<f64> {
+        let mut coo = nalgebra_sparse::CooMatrix::new(self.num_rows(), self.num_cols());
+        for (row_id, col_id, value) in self.matrix_entries() {
+            coo.push(row_id, col_id, value);
+        }
+        nalgebra_sparse::CscMatrix::from(&coo)
+    }
+}
+
+fn check_shape(
+    num_rows: usize,
+    num_cols: usize,
+    obj: &[f64],
+    col_bounds: &[(f64, f64)],
+    row_ranges: &[(f64, f64)],
+) {
+    assert_eq!(obj.len(), num_cols, "obj must have one entry per column");
+    assert_eq!(
+        col_bounds.len(),
+        num_cols,
+        "col_bounds must have one entry per column"
+    );
+    assert_eq!(
+        row_ranges.len(),
+        num_rows,
+        "row_ranges must have one entry per row"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Model, ObjSense, Status};
+
+    const OBJ: [f64; 3] = [1.0, 2.0, -1.0];
+    const COL_BOUNDS: [(f64, f64); 3] = [(0.0, 4.0), (0.0, 4.0), (0.0, 2.0)];
+    const ROW_RANGES: [(f64, f64); 2] = [(2.0, f64::INFINITY), (f64::NEG_INFINITY, 3.0)];
+
+    fn check(mut lp: Model) {
+        assert_eq!((lp.num_rows(), lp.num_cols()), (2, 3));
+        lp.set_obj_sense(ObjSense::Minimize);
+        let lp = lp.optimize();
+        assert_eq!(lp.status(), Status::Optimal);
+        assert!((lp.obj_val() - 1.0).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "sprs")]
+    #[test]
+    fn sprs_roundtrip() {
+        // x + y >= 2, x + z <= 3
+        let matrix = sprs::CsMat::new(
+            (2, 3),
+            vec![0, 2, 4],
+            vec![0, 1, 0, 2],
+            vec![1.0, 1.0, 1.0, 1.0],
+        );
+        let lp = Model::from_sparse(&matrix, &OBJ, &COL_BOUNDS, &ROW_RANGES);
+        assert_eq!(lp.constraint_matrix(), matrix);
+        check(lp);
+
+        let lp = Model::from_sparse(&matrix.to_csc(), &OBJ, &COL_BOUNDS, &ROW_RANGES);
+        assert_eq!(lp.constraint_matrix(), matrix);
+        check(lp);
+    }
+
+    #[cfg(feature = "nalgebra-sparse")]
+    #[test]
+    fn nalgebra_sparse_roundtrip() {
+        let mut coo = nalgebra_sparse::CooMatrix::new(2, 3);
+        coo.push(0, 0, 1.0);
+        coo.push(0, 1, 1.0);
+        coo.push(1, 0, 1.0);
+        coo.push(1, 2, 1.0);
+        let matrix = nalgebra_sparse::CscMatrix::from(&coo);
+        let lp = Model::from_csc(&matrix, &OBJ, &COL_BOUNDS, &ROW_RANGES);
+        assert_eq!(lp.constraint_matrix_csc(), matrix);
+        check(lp);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::model::LpData;
+use std::fmt;
+
+/// Statistics about the last call to `optimize` on a model.
+///
+/// The SoPlex C interface only reports the iteration count and the solving time directly,
+/// the basis related figures are computed from the final basis and solution.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SolveStatistics {
+    /// Number of simplex iterations.
+    pub num_iterations: i32,
+    /// Solving time in seconds.
+    pub solving_time: f64,
+    /// Number of columns that are basic in the final basis.
+    pub num_basic_cols: usize,
+    /// Number of rows (slacks) that are basic in the final basis.
+    pub num_basic_rows: usize,
+    /// Fraction of basic variables that sit at one of their bounds, between 0 and 1.
+    ///
+    /// `None` if no optimal solution is available.
+    pub primal_degeneracy: Option<f64>,
+    /// Fraction of nonbasic variables with a zero reduced cost, between 0 and 1.
+    ///
+    /// `None` if no optimal solution is available.
+    pub dual_degeneracy: Option<f64>,
+}
+
+/// Values whose magnitude or range exceeds this are reported as numerically problematic.
+const LARGE_VALUE: f64 = 1e9;
+
+/// Counts of the columns (rows) of a model by the kind of their bounds (sides).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BoundCounts {
+    /// Both bounds are infinite.
+    pub free: usize,
+    /// The lower and the upper bound are equal.
+    pub fixed: usize,
+    /// Both bounds are finite and differ.
+    pub boxed: usize,
+    /// Only the lower bound is finite.
+    pub lower: usize,
+    /// Only the upper bound is finite.
+    pub upper: usize,
+}
+
+impl BoundCounts {
+    fn add(&mut self, lower: Option<f64>, upper: Option<f64>) {
+        match (lower, upper) {
+            (None, None) => self.free += 1,
+            (Some(lower), Some(upper)) if lower == upper => self.fixed += 1,
+            (Some(_), Some(_)) => self.boxed += 1,
+            (Some(_), None) => self.lower += 1,
+            (None, Some(_)) => self.upper += 1,
+        }
+    }
+}
+
+/// The smallest and largest magnitude of the finite nonzero values of some kind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoefRange {
+    /// The smallest absolute value.
+    pub min: f64,
+    /// The largest absolute value.
+    pub max: f64,
+}
+
+impl CoefRange {
+    /// Returns the ratio of the largest to the smallest absolute value.
+    pub fn ratio(&self) -> f64 {
+        self.max / self.min
+    }
+
+    fn of(values: impl IntoIterator<Item = f64>) -> Option<Self> {
+        values
+            .into_iter()
+            .filter(|&value| value != 0.0)
+            .map(f64::abs)
+            .fold(None, |range, value| match range {
+                None => Some(Self {
+                    min: value,
+                    max: value,
+                }),
+                Some(range) => Some(Self {
+                    min: range.min.min(value),
+                    max: range.max.max(value),
+                }),
+            })
+    }
+}
+
+/// Statistics about the data of a model, see `Model::stats`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ModelStats {
+    /// Number of rows.
+    pub num_rows: usize,
+    /// Number of columns.
+    pub num_cols: usize,
+    /// Number of nonzeros in the constraint matrix.
+    pub num_nonzeros: usize,
+    /// The columns by the kind of their bounds.
+    pub col_bounds: BoundCounts,
+    /// The rows by the kind of their sides.
+    pub row_sides: BoundCounts,
+    /// Number of rows without nonzeros.
+    pub empty_rows: usize,
+    /// Number of columns without nonzeros.
+    pub empty_cols: usize,
+    /// Number of rows with a single nonzero.
+    pub singleton_rows: usize,
+    /// Number of columns with a single nonzero.
+    pub singleton_cols: usize,
+    /// Range of the constraint matrix, `None` if it has no nonzeros.
+    pub matrix_range: Option<CoefRange>,
+    /// Range of the objective, `None` if it is zero.
+    pub obj_range: Option<CoefRange>,
+    /// Range of the finite nonzero column bounds.
+    pub bounds_range: Option<CoefRange>,
+    /// Range of the finite nonzero row sides.
+    pub sides_range: Option<CoefRange>,
+    /// Ranges and values larger than 1e9 that are likely to cause numerical trouble.
+    pub warnings: Vec<String>,
+}
+
+impl ModelStats {
+    pub(crate) fn new(data: &LpData) -> Self {
+        let finite = |value: f64| data.is_finite(value).then_some(value);
+        let mut col_bounds = BoundCounts::default();
+        for &(lb, ub) in &data.col_bounds {
+            col_bounds.add(finite(lb), finite(ub));
+        }
+        let mut row_sides = BoundCounts::default();
+        for &(lhs, rhs) in &data.row_ranges {
+            row_sides.add(finite(lhs), finite(rhs));
+        }
+
+        let entries = || {
+            data.rows
+                .iter()
+                .flatten()
+                .filter(|&&(_, value)| value != 0.0)
+        };
+        let mut col_counts = vec![0; data.obj.len()];
+        for &(col_id, _) in entries() {
+            col_counts[col_id] += 1;
+        }
+        let row_counts: Vec<usize> = data
+            .rows
+            .iter()
+            .map(|row| row.iter().filter(|&&(_, value)| value != 0.0).count())
+            .collect();
+
+        let bound_values = |pairs: &[(f64, f64)]| -> Vec<f64> {
+            pairs
+                .iter()
+                .flat_map(|&(lower, upper)| [lower, upper])
+                .filter(|&value| data.is_finite(value))
+                .collect()
+        };
+        let bounds = bound_values(&data.col_bounds);
+        let sides = bound_values(&data.row_ranges);
+        let matrix_range = CoefRange::of(entries().map(|&(_, value)| value));
+        let obj_range = CoefRange::of(data.obj.iter().copied());
+        let bounds_range = CoefRange::of(bounds.iter().copied());
+        let sides_range = CoefRange::of(sides.iter().copied());
+
+        let mut warnings = Vec::new();
+        for (name, range) in [
+            ("matrix", matrix_range),
+            ("objective", obj_range),
+            ("bounds", bounds_range),
+            ("sides", sides_range),
+        ] {
+            let Some(range) = range else { continue };
+            if range.max > LARGE_VALUE {
+                warnings.push(format!("large {} value {:e}", name, range.max));
+            }
+            if range.ratio() > LARGE_VALUE {
+                warnings.push(format!(
+                    "{} range [{:e}, {:e}] spans more than 1e9",
+                    name, range.min, range.max
+                ));
+            }
+        }
+
+        Self {
+            num_rows: data.rows.len(),
+            num_cols: data.obj.len(),
+            num_nonzeros: entries().count(),
+            col_bounds,
+            row_sides,
+            empty_rows: row_counts.iter().filter(|&&count| count == 0).count(),
+            empty_cols: col_counts.iter().filter(|&&count| count == 0).count(),
+            singleton_rows: row_counts.iter().filter(|&&count| count == 1).count(),
+            singleton_cols: col_counts.iter().filter(|&&count| count == 1).count(),
+            matrix_range,
+            obj_range,
+            bounds_range,
+            sides_range,
+            warnings,
+        }
+    }
+}
+
+impl fmt::Display for ModelStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} rows, {} columns and {} nonzeros",
+            self.num_rows, self.num_cols, self.num_nonzeros
+        )?;
+        for (name, counts) in [("columns", self.col_bounds), ("rows", self.row_sides)] {
+            writeln!(
+                f,
+                "  {}: {} free, {} fixed, {} boxed, {} lower, {} upper",
+                name, counts.free, counts.fixed, counts.boxed, counts.lower, counts.upper
+            )?;
+        }
+        writeln!(
+            f,
+            "  {} empty rows, {} empty columns, {} singleton rows, {} singleton columns",
+            self.empty_rows, self.empty_cols, self.singleton_rows, self.singleton_cols
+        )?;
+        writeln!(f, "Coefficient statistics:")?;
+        for (name, range) in [
+            ("Matrix", self.matrix_range),
+            ("Objective", self.obj_range),
+            ("Bounds", self.bounds_range),
+            ("Sides", self.sides_range),
+        ] {
+            match range {
+                Some(range) => writeln!(
+                    f,
+                    "  {:<9} range [{:.0e}, {:.0e}]",
+                    name, range.min, range.max
+                )?,
+                None => writeln!(f, "  {:<9} range -", name)?,
+            }
+        }
+        for warning in &self.warnings {
+            writeln!(f, "Warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::convert::From;
 
 /// Status of the solver
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Status {
     /// An error occurred
     Error,
@@ -0,0 +1,288 @@
+use crate::{ColId, Model, RowId};
+
+/// At most this fraction of the rows (columns) is tried as linking rows (columns).
+const MAX_LINKING_FRACTION: f64 = 0.2;
+
+/// A block of the constraint matrix, see `Model::structure`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    /// The rows of the block, in increasing order.
+    pub rows: Vec<RowId>,
+    /// The columns of the block, in increasing order.
+    pub cols: Vec<ColId>,
+}
+
+/// The block structure of the constraint matrix of a model, see `Model::structure`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Structure {
+    /// The blocks, ordered by their first row.
+    pub blocks: Vec<Block>,
+    /// The rows that link the blocks and the rows without entries in any block.
+    pub linking_rows: Vec<RowId>,
+    /// The columns that link the blocks and the columns that only appear in linking rows.
+    pub linking_cols: Vec<ColId>,
+}
+
+impl Structure {
+    /// Returns whether the matrix has at least two blocks, e.g. to decide whether to decompose the
+    /// model with `Benders` or by hand.
+    pub fn is_decomposable(&self) -> bool {
+        self.blocks.len() > 1
+    }
+}
+
+impl Model {
+    /// Detects the block structure of the constraint matrix.
+    ///
+    /// The blocks are the connected components of the bipartite graph of rows and columns with an
+    /// edge for every nonzero. If the matrix is connected, the densest rows are taken as linking rows
+    /// one after another until it falls apart (block-angular structure for Dantzig-Wolfe), otherwise
+    /// the densest columns are taken as linking columns (dual block-angular structure for Benders).
+    /// At most a fifth of the rows or columns is taken, if that is not enough a single block is
+    /// reported. Rows without entries in any block are reported as linking rows.
+    pub fn structure(&self) -> Structure {
+        let rows: Vec<Vec<ColId>> = self
+            .lp_data()
+            .rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .filter(|&(_, value)| value != 0.0)
+                    .map(|(col_id, _)| col_id)
+                    .collect()
+            })
+            .collect();
+        let num_rows = rows.len();
+        let num_cols = self.num_cols();
+
+        let structure = components(&rows, num_cols, &[], &[]);
+        if structure.is_decomposable() {
+            return structure;
+        }
+
+        let mut cols: Vec<Vec<RowId>> = vec![Vec::new(); num_cols];
+        for (row_id, row) in rows.iter().enumerate() {
+            for &col_id in row {
+                cols[col_id].push(row_id);
+            }
+        }
+        let mut dense_rows: Vec<RowId> = (0..num_rows).collect();
+        dense_rows.sort_by_key(|&row_id| std::cmp::Reverse(rows[row_id].len()));
+        let mut dense_cols: Vec<ColId> = (0..num_cols).collect();
+        dense_cols.sort_by_key(|&col_id| std::cmp::Reverse(cols[col_id].len()));
+
+        // the rows are added sparsest first, once all but the k densest ones are added the number
+        // of blocks is that of the matrix without them, the last decomposable k is the smallest one
+        let max_rows = (MAX_LINKING_FRACTION * num_rows as f64) as usize;
+        let mut union_find = UnionFind::new(num_rows + num_cols);
+        let mut num_linking = None;
+        for (k, &row_id) in dense_rows.iter().enumerate().rev() {
+            for &col_id in &rows[row_id] {
+                union_find.add(row_id, num_rows + col_id);
+            }
+            if (1..=max_rows).contains(&k) && union_find.num_blocks > 1 {
+                num_linking = Some(k);
+            }
+        }
+        if let Some(k) = num_linking {
+            return components(&rows, num_cols, &dense_rows[..k], &[]);
+        }
+
+        let max_cols = (MAX_LINKING_FRACTION * num_cols as f64) as usize;
+        let mut union_find = UnionFind::new(num_rows + num_cols);
+        for (k, &col_id) in dense_cols.iter().enumerate().rev() {
+            for &row_id in &cols[col_id] {
+                union_find.add(row_id, num_rows + col_id);
+            }
+            if (1..=max_cols).contains(&k) && union_find.num_blocks > 1 {
+                num_linking = Some(k);
+            }
+        }
+        match num_linking {
+            Some(k) => components(&rows, num_cols, &[], &dense_cols[..k]),
+            None => structure,
+        }
+    }
+}
+
+/// Union-find over the rows followed by the columns that counts the blocks, i.e. the components
+/// with at least one row, while nonzeros are added.
+struct UnionFind {
+    parent: Vec<usize>,
+    /// Whether the component of a root contains a row, a row only counts once it has a nonzero.
+    has_row: Vec<bool>,
+    num_blocks: usize,
+}
+
+impl UnionFind {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            parent: (0..num_nodes).collect(),
+            has_row: vec![false; num_nodes],
+            num_blocks: 0,
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    /// Adds a nonzero between a row node and a column node.
+    fn add(&mut self, row: usize, col: usize) {
+        let a = self.find(row);
+        if !self.has_row[a] {
+            // the first nonzero of the row
+            self.has_row[a] = true;
+            self.num_blocks += 1;
+        }
+        let b = self.find(col);
+        if a != b {
+            if self.has_row[b] {
+                self.num_blocks -= 1;
+            }
+            self.parent[b] = a;
+        }
+    }
+}
+
+/// Returns the connected components of the matrix without the given linking rows and columns.
+fn components(
+    rows: &[Vec<ColId>],
+    num_cols: usize,
+    linking_rows: &[RowId],
+    linking_cols: &[ColId],
+) -> Structure {
+    let num_rows = rows.len();
+    let mut is_linking_row = vec![false; num_rows];
+    for &row_id in linking_rows {
+        is_linking_row[row_id] = true;
+    }
+    let mut is_linking_col = vec![false; num_cols];
+    for &col_id in linking_cols {
+        is_linking_col[col_id] = true;
+    }
+
+    let mut union_find = UnionFind::new(num_rows + num_cols);
+    for (row_id, row) in rows.iter().enumerate() {
+        if is_linking_row[row_id] {
+            continue;
+        }
+        for &col_id in row.iter().filter(|&&col_id| !is_linking_col[col_id]) {
+            union_find.add(row_id, num_rows + col_id);
+        }
+    }
+
+    // blocks are numbered in the order of their first row
+    let mut block_of = vec![usize::MAX; num_rows + num_cols];
+    let mut structure = Structure::default();
+    for (row_id, &is_linking) in is_linking_row.iter().enumerate() {
+        let root = union_find.find(row_id);
+        if is_linking || !union_find.has_row[root] {
+            structure.linking_rows.push(row_id);
+            continue;
+        }
+        if block_of[root] == usize::MAX {
+            block_of[root] = structure.blocks.len();
+            structure.blocks.push(Block::default());
+        }
+        structure.blocks[block_of[root]].rows.push(row_id);
+    }
+    for (col_id, &is_linking) in is_linking_col.iter().enumerate() {
+        let idx = block_of[union_find.find(num_rows + col_id)];
+        if idx == usize::MAX || is_linking {
+            structure.linking_cols.push(col_id);
+        } else {
+            structure.blocks[idx].cols.push(col_id);
+        }
+    }
+    structure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two blocks of two columns each, optionally linked by a row over all columns.
+    fn two_blocks(linked: bool) -> Model {
+        let mut lp = Model::new();
+        for _ in 0..4 {
+            lp.add_col(vec![], 1.0, 0.0, 1.0);
+        }
+        lp.add_row(vec![1.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 0.0, 1.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![1.0, 0.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 0.0, 0.0, 1.0], 0.0, 1.0);
+        if linked {
+            lp.add_row(vec![1.0; 4], 0.0, 3.0);
+        }
+        lp
+    }
+
+    #[test]
+    fn structure_components() {
+        let structure = two_blocks(false).structure();
+        assert!(structure.is_decomposable());
+        assert_eq!(
+            structure.blocks,
+            vec![
+                Block {
+                    rows: vec![0, 2],
+                    cols: vec![0, 1]
+                },
+                Block {
+                    rows: vec![1, 3],
+                    cols: vec![2, 3]
+                }
+            ]
+        );
+        assert!(structure.linking_rows.is_empty() && structure.linking_cols.is_empty());
+    }
+
+    #[test]
+    fn structure_linking_row() {
+        let structure = two_blocks(true).structure();
+        assert_eq!(structure.blocks.len(), 2);
+        assert_eq!(structure.linking_rows, vec![4]);
+        assert!(structure.linking_cols.is_empty());
+    }
+
+    #[test]
+    fn structure_linking_col() {
+        // x2 appears in most rows, removing a single row does not split the matrix
+        let mut lp = Model::new();
+        for _ in 0..5 {
+            lp.add_col(vec![], 1.0, 0.0, 1.0);
+        }
+        lp.add_row(vec![1.0, 0.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 1.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![1.0, 0.0, 1.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 1.0, 1.0, 0.0, 1.0], 0.0, 1.0);
+        lp.add_row(vec![0.0, 0.0, 1.0, 1.0], 0.0, 1.0);
+        let structure = lp.structure();
+        assert_eq!(structure.linking_cols, vec![2]);
+        assert!(structure.linking_rows.is_empty());
+        assert_eq!(structure.blocks.len(), 2);
+    }
+
+    #[test]
+    fn structure_empty_row() {
+        let mut lp = two_blocks(false);
+        lp.add_row(vec![], 0.0, 1.0);
+        let structure = lp.structure();
+        assert_eq!(structure.blocks.len(), 2);
+        assert_eq!(structure.linking_rows, vec![4]);
+
+        // a connected matrix with an empty row is a single block
+        let mut lp = Model::new();
+        lp.add_col(vec![], 1.0, 0.0, 1.0);
+        lp.add_row(vec![1.0], 0.0, 1.0);
+        lp.add_row(vec![0.0], 0.0, 1.0);
+        assert!(!lp.structure().is_decomposable());
+    }
+}